@@ -0,0 +1,36 @@
+use crate::errors::EmojiError;
+use tauri::AppHandle;
+use tauri_plugin_autostart::ManagerExt;
+
+/// Reconcile the OS-level launch agent with the `start_on_login` setting.
+/// Failures are logged rather than propagated since this is a background
+/// accessory app and shouldn't refuse to start over a launch-agent hiccup.
+pub fn reconcile(app_handle: &AppHandle, enabled: bool) {
+    let autolaunch = app_handle.autolaunch();
+
+    let is_enabled = match autolaunch.is_enabled() {
+        Ok(is_enabled) => is_enabled,
+        Err(e) => {
+            log::warn!("Failed to read autostart state: {}", e);
+            return;
+        }
+    };
+
+    if enabled == is_enabled {
+        return;
+    }
+
+    let result: Result<(), EmojiError> = if enabled {
+        autolaunch
+            .enable()
+            .map_err(|e| EmojiError::Tauri(format!("Failed to enable autostart: {}", e)))
+    } else {
+        autolaunch
+            .disable()
+            .map_err(|e| EmojiError::Tauri(format!("Failed to disable autostart: {}", e)))
+    };
+
+    if let Err(e) = result {
+        log::warn!("{}", e);
+    }
+}
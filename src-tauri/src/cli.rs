@@ -0,0 +1,106 @@
+use crate::command;
+use crate::AppState;
+use tauri::{AppHandle, Manager};
+
+/// A CLI subcommand forwarded to the (already running) app instance, mirroring
+/// creddy's `run`/`get`/`exec`/`shortcut` split between launching the GUI and
+/// driving it from the terminal.
+#[derive(Debug, PartialEq)]
+enum CliCommand {
+    Show,
+    Hide,
+    Type(String),
+}
+
+fn parse_args(args: &[String]) -> Option<CliCommand> {
+    // args[0] is the binary path; the subcommand (if any) follows
+    match args.get(1).map(String::as_str) {
+        Some("show") => Some(CliCommand::Show),
+        Some("hide") => Some(CliCommand::Hide),
+        // Join any trailing args with spaces so an unquoted shortcode (or
+        // emoji split across argv by the shell) still arrives as one value.
+        Some("type") => {
+            let rest = args.get(2..).unwrap_or(&[]).join(" ");
+            (!rest.is_empty()).then_some(CliCommand::Type(rest))
+        }
+        _ => None,
+    }
+}
+
+/// Parse argv (ours, or forwarded from a second launch via single-instance)
+/// and dispatch to the matching Tauri command on the running app.
+pub fn dispatch_args(app: &AppHandle, args: &[String]) {
+    let Some(cmd) = parse_args(args) else {
+        return;
+    };
+
+    log::info!("Dispatching CLI command: {:?}", cmd);
+
+    match cmd {
+        CliCommand::Show => {
+            if let Err(e) = command::show_panel(app.clone()) {
+                log::error!("CLI 'show' failed: {}", e);
+            }
+        }
+        CliCommand::Hide => {
+            if let Err(e) = command::hide_panel(app.clone()) {
+                log::error!("CLI 'hide' failed: {}", e);
+            }
+        }
+        CliCommand::Type(emoji) => {
+            let Some(state) = app.try_state::<AppState>() else {
+                log::error!("CLI 'type' failed: app state not ready");
+                return;
+            };
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = command::type_emoji(handle, state, emoji).await {
+                    log::error!("CLI 'type' failed: {}", e);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_show() {
+        let args = vec!["emojiq".to_string(), "show".to_string()];
+        assert_eq!(parse_args(&args), Some(CliCommand::Show));
+    }
+
+    #[test]
+    fn parses_type_with_emoji() {
+        let args = vec!["emojiq".to_string(), "type".to_string(), "😀".to_string()];
+        assert_eq!(parse_args(&args), Some(CliCommand::Type("😀".to_string())));
+    }
+
+    #[test]
+    fn parses_type_with_unquoted_shortcode() {
+        let args = vec![
+            "emojiq".to_string(),
+            "type".to_string(),
+            ":grinning".to_string(),
+            "face:".to_string(),
+        ];
+        assert_eq!(
+            parse_args(&args),
+            Some(CliCommand::Type(":grinning face:".to_string()))
+        );
+    }
+
+    #[test]
+    fn type_without_emoji_is_ignored() {
+        let args = vec!["emojiq".to_string(), "type".to_string()];
+        assert_eq!(parse_args(&args), None);
+    }
+
+    #[test]
+    fn no_subcommand_launches_gui() {
+        let args = vec!["emojiq".to_string()];
+        assert_eq!(parse_args(&args), None);
+    }
+}
@@ -1,15 +1,18 @@
 use crate::constants::{
-    FOCUS_RESTORATION_DELAY_MS, HOTKEY_UNREGISTER_WAIT_MS, MAX_SCALE_FACTOR, MAX_TOP_EMOJIS_LIMIT,
-    MIN_SCALE_FACTOR,
+    CLIPBOARD_PASTE_SETTLE_MS, FOCUS_RESTORATION_DELAY_MS, FREQUENTLY_USED_CATEGORY,
+    HOTKEY_UNREGISTER_WAIT_MS, MAX_IDLE_TIMEOUT_SECS, MAX_SCALE_FACTOR, MAX_TOP_EMOJIS_LIMIT,
+    MIN_SCALE_FACTOR, RECENT_CATEGORY,
 };
 use crate::errors::EmojiError;
 use crate::hotkey;
 use crate::panel;
 use crate::permissions::{ensure_accessibility_permission, reset_permission_cache};
-use crate::settings::{EmojiMode, Settings as AppSettings};
+use crate::settings::{EmojiMode, PasteStrategy, Settings as AppSettings};
+use crate::shortcodes::{self, ShortcodeMatch};
 use crate::tray;
 use crate::AppState;
-use enigo::{Enigo, Keyboard, Settings};
+use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+use std::collections::HashMap;
 use tauri::{AppHandle, Emitter, Manager, State};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 use tauri_plugin_global_shortcut::GlobalShortcutExt;
@@ -32,13 +35,9 @@ async fn copy_emoji(handle: &AppHandle, emoji: &str) -> Result<(), EmojiError> {
         .map_err(|e| EmojiError::Tauri(format!("Failed to copy emoji to clipboard: {}", e)))
 }
 
-/// Paste emoji to the previously focused window
-async fn paste_emoji(emoji: &str) -> Result<(), EmojiError> {
-    // Panel is already hidden and focus to the previously active application is being restored
-    // Short delay to allow focus restoration to complete (offload blocking sleep)
-    let delay = std::time::Duration::from_millis(FOCUS_RESTORATION_DELAY_MS);
-    let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
-
+/// Type `emoji` as synthetic text input via Enigo. Fast, but unreliable for
+/// multi-codepoint sequences (ZWJ, skin tones, flags) in some apps.
+fn type_emoji_as_text(emoji: &str) -> Result<(), EmojiError> {
     let mut enigo = Enigo::new(&Settings::default())
         .map_err(|e| EmojiError::Tauri(format!("Failed to initialize Enigo: {}", e)))?;
 
@@ -47,27 +46,112 @@ async fn paste_emoji(emoji: &str) -> Result<(), EmojiError> {
         .map_err(|e| EmojiError::Tauri(format!("Failed to type emoji: {}", e)))
 }
 
+/// Write `emoji` to the clipboard and synthesize the platform paste shortcut
+/// (Cmd+V / Ctrl+V), restoring whatever was previously on the clipboard
+/// afterward. Works for emoji that synthetic text input mangles.
+async fn paste_emoji_via_clipboard(handle: &AppHandle, emoji: &str) -> Result<(), EmojiError> {
+    let previous_clipboard = handle.clipboard().read_text().ok();
+
+    copy_emoji(handle, emoji).await?;
+
+    {
+        let mut enigo = Enigo::new(&Settings::default())
+            .map_err(|e| EmojiError::Tauri(format!("Failed to initialize Enigo: {}", e)))?;
+
+        let paste_modifier = if cfg!(target_os = "macos") {
+            Key::Meta
+        } else {
+            Key::Control
+        };
+
+        enigo
+            .key(paste_modifier, Direction::Press)
+            .map_err(|e| EmojiError::Tauri(format!("Failed to press paste modifier: {}", e)))?;
+        enigo
+            .key(Key::Unicode('v'), Direction::Click)
+            .map_err(|e| EmojiError::Tauri(format!("Failed to synthesize paste: {}", e)))?;
+        enigo
+            .key(paste_modifier, Direction::Release)
+            .map_err(|e| EmojiError::Tauri(format!("Failed to release paste modifier: {}", e)))?;
+    }
+
+    let delay = std::time::Duration::from_millis(CLIPBOARD_PASTE_SETTLE_MS);
+    let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
+
+    if let Some(previous) = previous_clipboard {
+        if let Err(e) = handle.clipboard().write_text(previous) {
+            log::warn!("Failed to restore previous clipboard contents: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Paste emoji to the previously focused window, using the configured
+/// `PasteStrategy` and falling back to the clipboard-paste path if synthetic
+/// text input fails.
+async fn paste_emoji(
+    handle: &AppHandle,
+    emoji: &str,
+    strategy: PasteStrategy,
+) -> Result<(), EmojiError> {
+    // Panel is already hidden and focus to the previously active application is being restored
+    // Short delay to allow focus restoration to complete (offload blocking sleep)
+    let delay = std::time::Duration::from_millis(FOCUS_RESTORATION_DELAY_MS);
+    let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
+
+    if strategy == PasteStrategy::ClipboardPaste {
+        return paste_emoji_via_clipboard(handle, emoji).await;
+    }
+
+    if let Err(e) = type_emoji_as_text(emoji) {
+        log::warn!(
+            "Text-input paste failed ({}), falling back to clipboard paste",
+            e
+        );
+        return paste_emoji_via_clipboard(handle, emoji).await;
+    }
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn type_emoji(
     handle: AppHandle,
     state: State<'_, AppState>,
     emoji: String,
 ) -> Result<(), EmojiError> {
+    panel::reset_idle_timer(&handle);
+
+    // Allow callers (CLI, frontend shortcode autocomplete) to pass a
+    // `:shortcode:` directly instead of resolving it themselves first.
+    let emoji = match emoji.strip_prefix(':').and_then(|s| s.strip_suffix(':')) {
+        Some(name) => shortcodes::resolve(&name.to_lowercase())
+            .map(str::to_string)
+            .unwrap_or(emoji),
+        None => emoji,
+    };
+
     // Get current settings to determine emoji mode
     let settings = state.settings_manager.get()?;
 
     match settings.emoji_mode {
         EmojiMode::PasteOnly => {
             ensure_accessibility_permission().await?;
-            paste_emoji(&emoji).await?;
+            paste_emoji(&handle, &emoji, settings.paste_strategy).await?;
         }
         EmojiMode::CopyOnly => {
             copy_emoji(&handle, &emoji).await?;
+            // Marks this session eligible for `Settings::auto_paste`; consumed
+            // (and reset) by `restore_previous_app` once focus comes back.
+            state
+                .pending_auto_paste
+                .store(true, std::sync::atomic::Ordering::SeqCst);
         }
         EmojiMode::PasteAndCopy => {
             ensure_accessibility_permission().await?;
             copy_emoji(&handle, &emoji).await?;
-            paste_emoji(&emoji).await?;
+            paste_emoji(&handle, &emoji, settings.paste_strategy).await?;
         }
     }
 
@@ -79,9 +163,52 @@ pub fn reset_accessibility_cache() {
     reset_permission_cache();
 }
 
+/// Re-type the top emoji of `category` (the "Recent"/"Frequently Used"
+/// pseudo-categories) without opening the panel, so a dedicated hotkey can
+/// drive it directly. A no-op if nothing in that category has been used yet.
+async fn paste_top_from_category(
+    handle: AppHandle,
+    state: State<'_, AppState>,
+    category: &str,
+) -> Result<(), EmojiError> {
+    let Some(emoji) = state
+        .emoji_manager
+        .get_emojis_by_category(category, 1)?
+        .into_iter()
+        .next()
+    else {
+        log::debug!("No emoji in category '{}' to paste yet", category);
+        return Ok(());
+    };
+
+    type_emoji(handle, state, emoji).await
+}
+
+#[tauri::command]
+pub async fn paste_recent_emoji(
+    handle: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), EmojiError> {
+    paste_top_from_category(handle, state, RECENT_CATEGORY).await
+}
+
+#[tauri::command]
+pub async fn paste_top_ranked_emoji(
+    handle: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), EmojiError> {
+    paste_top_from_category(handle, state, FREQUENTLY_USED_CATEGORY).await
+}
+
 // Emoji manager commands
 #[tauri::command]
-pub fn get_emojis(state: State<AppState>, filter_word: String) -> Result<Vec<String>, EmojiError> {
+pub fn get_emojis(
+    handle: AppHandle,
+    state: State<AppState>,
+    filter_word: String,
+) -> Result<Vec<String>, EmojiError> {
+    panel::reset_idle_timer(&handle);
+
     let settings = state.settings_manager.get()?;
     state
         .emoji_manager
@@ -93,6 +220,47 @@ pub fn get_keywords(state: State<AppState>, emoji: String) -> Result<Vec<String>
     state.emoji_manager.get_keywords(&emoji)
 }
 
+#[tauri::command]
+pub fn get_variants(state: State<AppState>, emoji: String) -> Result<Vec<String>, EmojiError> {
+    state.emoji_manager.get_variants(&emoji)
+}
+
+/// Scan `text` for `:shortcode:` tokens, e.g. for inline autocomplete/one-shot
+/// replacement in a text field, without round-tripping per keystroke.
+#[tauri::command]
+pub fn resolve_shortcode(text: String) -> Result<Vec<ShortcodeMatch>, EmojiError> {
+    Ok(shortcodes::scan(&text))
+}
+
+#[tauri::command]
+pub fn get_categories(state: State<AppState>) -> Result<Vec<String>, EmojiError> {
+    state.emoji_manager.get_categories()
+}
+
+#[tauri::command]
+pub fn reload_emoji_packs(handle: AppHandle, state: State<AppState>) -> Result<(), EmojiError> {
+    state.emoji_manager.reload_packs()?;
+
+    // Notify main window to refresh emoji list if it exists
+    if let Some(main_window) = handle.get_webview_window("main") {
+        let _ = main_window.emit("settings-changed", ());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_emojis_by_category(
+    state: State<AppState>,
+    category: String,
+    max: Option<usize>,
+) -> Result<Vec<String>, EmojiError> {
+    let settings = state.settings_manager.get()?;
+    state
+        .emoji_manager
+        .get_emojis_by_category(&category, max.unwrap_or(settings.max_top_emojis))
+}
+
 #[tauri::command]
 pub fn increment_usage(state: State<AppState>, emoji: String, amount: Option<u32>) -> Result<(), EmojiError> {
     state.emoji_manager.increment_usage(&emoji, amount)
@@ -152,28 +320,69 @@ pub async fn update_settings(
         new_settings.max_top_emojis = MAX_TOP_EMOJIS_LIMIT;
     }
 
-    // Validate hotkey string by parsing
-    if let Err(e) = crate::hotkey::parse_hotkey(&new_settings.global_hotkey) {
-        return Err(EmojiError::InvalidInput(format!(
-            "Invalid hotkey '{}': {}",
-            new_settings.global_hotkey, e
-        )));
+    if new_settings.idle_timeout > MAX_IDLE_TIMEOUT_SECS {
+        new_settings.idle_timeout = MAX_IDLE_TIMEOUT_SECS;
     }
 
-    // Check if hotkey has changed
+    // Validate every configured hotkey string by parsing, then echo back its
+    // normalized rendering so the UI always shows a consistent form
+    // regardless of how the user typed the accelerator.
+    let mut normalized_hotkeys = HashMap::with_capacity(new_settings.hotkeys.len());
+    for (action, hotkey_str) in &new_settings.hotkeys {
+        let normalized = hotkey::normalize_hotkey(hotkey_str).map_err(|e| {
+            EmojiError::InvalidInput(format!(
+                "Invalid hotkey '{}' for {:?}: {}",
+                hotkey_str, action, e
+            ))
+        })?;
+        normalized_hotkeys.insert(*action, normalized);
+    }
+    new_settings.hotkeys = normalized_hotkeys;
+
+    // Check if any hotkey has changed
     let old_settings = state.settings_manager.get()?;
-    let hotkey_changed = old_settings.global_hotkey != new_settings.global_hotkey;
+    let hotkey_changed = old_settings.hotkeys != new_settings.hotkeys;
 
     if hotkey_changed {
         log::info!(
-            "Hotkey changed from '{}' to '{}'",
-            old_settings.global_hotkey,
-            new_settings.global_hotkey
+            "Hotkeys changed from {:?} to {:?}",
+            old_settings.hotkeys,
+            new_settings.hotkeys
         );
     }
 
     state.settings_manager.update(new_settings.clone())?;
 
+    if old_settings.start_on_login != new_settings.start_on_login {
+        crate::autostart::reconcile(&handle, new_settings.start_on_login);
+    }
+
+    if old_settings.search_language != new_settings.search_language {
+        if let Err(e) = state
+            .emoji_manager
+            .set_search_language(new_settings.search_language)
+        {
+            log::warn!("Failed to switch search language: {}", e);
+        }
+    }
+
+    if old_settings.default_skin_tone != new_settings.default_skin_tone {
+        if let Err(e) = state
+            .emoji_manager
+            .set_default_skin_tone(new_settings.default_skin_tone)
+        {
+            log::warn!("Failed to update default skin tone: {}", e);
+        }
+    }
+
+    if old_settings.visible_on_all_workspaces != new_settings.visible_on_all_workspaces {
+        if let Err(e) =
+            panel::set_visible_on_all_workspaces(&handle, new_settings.visible_on_all_workspaces)
+        {
+            log::warn!("Failed to update panel workspace visibility: {}", e);
+        }
+    }
+
     // Notify main window to refresh emoji list if it exists
     if let Some(main_window) = handle.get_webview_window("main") {
         let _ = main_window.emit("settings-changed", ());
@@ -182,8 +391,17 @@ pub async fn update_settings(
     // Re-register hotkey if it changed
     if hotkey_changed {
         log::info!("Hotkey changed, re-registering...");
-        if let Err(e) = reregister_hotkey(handle.clone(), state).await {
-            log::error!("Failed to re-register hotkey: {}", e);
+        if let Err(e) = reregister_hotkey(handle.clone(), state.clone()).await {
+            log::error!("Failed to re-register hotkey, reverting to previous bindings: {}", e);
+
+            // Don't leave the persisted settings pointing at a binding the OS
+            // refused; keep everything else from this update, but restore
+            // the hotkeys that are actually active again.
+            let mut reverted_settings = new_settings;
+            reverted_settings.hotkeys = old_settings.hotkeys;
+            state.settings_manager.update(reverted_settings)?;
+
+            return Err(e);
         }
     }
 
@@ -236,7 +454,20 @@ pub fn close_help(handle: AppHandle) -> Result<(), EmojiError> {
 
 #[tauri::command]
 pub fn save_window_size(state: State<AppState>, width: f64, height: f64) -> Result<(), EmojiError> {
-    state.settings_manager.update_window_size(width, height)
+    state.settings_manager.update_window_size(width, height)?;
+    state.window_state_manager.update_size(width, height)
+}
+
+#[tauri::command]
+pub fn save_window_position(state: State<AppState>, x: f64, y: f64) -> Result<(), EmojiError> {
+    state.window_state_manager.update_position(x, y)
+}
+
+/// Parse and reformat a hotkey string into its canonical display form, so
+/// the settings UI can preview it live as the user types an accelerator.
+#[tauri::command]
+pub fn normalize_hotkey(hotkey_str: String) -> Result<String, EmojiError> {
+    hotkey::normalize_hotkey(&hotkey_str).map_err(EmojiError::InvalidInput)
 }
 
 #[tauri::command]
@@ -244,23 +475,26 @@ pub async fn reregister_hotkey(
     handle: AppHandle,
     state: State<'_, AppState>,
 ) -> Result<(), EmojiError> {
-    log::info!("Re-registering hotkey...");
+    log::info!("Re-registering hotkeys...");
 
     // Make sure panel is hidden before re-registering
     log::debug!("Ensuring panel is hidden before re-registration...");
     let _ = hide_panel(handle.clone());
 
-    // Get the new hotkey from settings
+    // Get the configured hotkeys from settings
     let settings = state.settings_manager.get()?;
-    let new_hotkey_str = settings.global_hotkey.clone();
 
-    // Parse the new hotkey
-    let new_shortcut = hotkey::parse_hotkey(&new_hotkey_str).map_err(|e| {
-        EmojiError::InvalidInput(format!(
-            "Failed to parse hotkey '{}': {}",
-            new_hotkey_str, e
-        ))
-    })?;
+    // Parse every hotkey before touching anything currently registered
+    let mut parsed = Vec::with_capacity(settings.hotkeys.len());
+    for (action, hotkey_str) in &settings.hotkeys {
+        let shortcut = hotkey::parse_hotkey(hotkey_str).map_err(|e| {
+            EmojiError::InvalidInput(format!(
+                "Failed to parse hotkey '{}' for {:?}: {}",
+                hotkey_str, action, e
+            ))
+        })?;
+        parsed.push((*action, shortcut));
+    }
 
     // Unregister ALL shortcuts to ensure clean state
     log::debug!("Unregistering all shortcuts");
@@ -274,22 +508,70 @@ pub async fn reregister_hotkey(
     let delay = std::time::Duration::from_millis(HOTKEY_UNREGISTER_WAIT_MS);
     let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
 
-    // Register the new shortcut (single global handler will handle events)
-    log::debug!("Registering new hotkey: {}", new_hotkey_str);
-    handle
-        .global_shortcut()
-        .register(new_shortcut)
-        .map_err(|e| EmojiError::Tauri(format!("Failed to register new hotkey: {}", e)))?;
+    // Snapshot the previous known-good bindings so we can fall back to them
+    // if one of the new shortcuts can't be claimed (e.g. the OS or another
+    // app already owns it).
+    let previous = state
+        .registered_hotkeys
+        .lock()
+        .map_err(|e| EmojiError::Lock(format!("Failed to lock hotkeys: {}", e)))?
+        .clone();
+
+    // Register each shortcut (single global handler routes events by action)
+    let mut registered = HashMap::with_capacity(parsed.len());
+    let mut register_error = None;
+    for (action, shortcut) in parsed {
+        log::debug!("Registering hotkey for {:?}", action);
+        if let Err(e) = handle.global_shortcut().register(shortcut) {
+            register_error = Some((action, e.to_string()));
+            break;
+        }
+        registered.insert(shortcut, action);
+    }
+
+    if let Some((failed_action, message)) = register_error {
+        log::error!(
+            "Failed to register hotkey for {:?}: {}. Restoring previous bindings.",
+            failed_action,
+            message
+        );
+
+        // Best-effort: drop whatever partially registered, then restore the
+        // previous known-good shortcuts so the app isn't left unresponsive.
+        let _ = handle.global_shortcut().unregister_all();
+        for shortcut in previous.keys() {
+            if let Err(e) = handle.global_shortcut().register(*shortcut) {
+                log::error!("Failed to restore previous hotkey: {}", e);
+            }
+        }
+
+        {
+            let mut current = state
+                .registered_hotkeys
+                .lock()
+                .map_err(|e| EmojiError::Lock(format!("Failed to lock hotkeys: {}", e)))?;
+            *current = previous;
+        }
+
+        if let Some(settings_window) = handle.get_webview_window("settings") {
+            let _ = settings_window.emit("hotkey-registration-failed", failed_action);
+        }
+
+        return Err(EmojiError::HotkeyConflict(format!(
+            "Failed to register hotkey for {:?}: {}",
+            failed_action, message
+        )));
+    }
 
-    // Update the stored shortcut
+    // Update the stored shortcut -> action map the global handler looks up
     {
         let mut current = state
-            .current_shortcut
+            .registered_hotkeys
             .lock()
-            .map_err(|e| EmojiError::Lock(format!("Failed to lock shortcut: {}", e)))?;
-        *current = new_shortcut;
+            .map_err(|e| EmojiError::Lock(format!("Failed to lock hotkeys: {}", e)))?;
+        *current = registered;
     }
 
-    log::info!("Hotkey successfully re-registered to: {}", new_hotkey_str);
+    log::info!("Hotkeys successfully re-registered");
     Ok(())
 }
@@ -5,6 +5,10 @@ pub const FOCUS_RESTORATION_DELAY_MS: u64 = 200;
 pub const RANK_WRITE_DELAY_SECS: u64 = 2;
 pub const SETTINGS_HIDE_BEFORE_OPEN_MS: u64 = 100;
 pub const HOTKEY_UNREGISTER_WAIT_MS: u64 = 300;
+// Gives the OS time to process the synthesized paste keystroke before we
+// restore whatever was on the clipboard beforehand.
+pub const CLIPBOARD_PASTE_SETTLE_MS: u64 = 150;
+pub const SETTINGS_WATCH_DEBOUNCE_MS: u64 = 250;
 // pub const ASYNC_WRITE_CHECK_DELAY_MS: u64 = 100; // Currently unused
 
 // Search constants
@@ -13,13 +17,51 @@ pub const MAX_SEARCH_RESULTS: usize = 2000;
 pub const MIN_KEYWORD_LENGTH: usize = 2;
 pub const MAX_PREFIX_LENGTH: usize = 12; // Cap for prefix indexing to bound memory
 
+// Fuzzy fallback: triggers when the exact/prefix index yields fewer than this
+// many hits for a query long enough to search; queries at or under the short
+// length get a tighter edit-distance cap since typos matter more proportionally.
+pub const FUZZY_MATCH_TRIGGER_RESULTS: usize = 5;
+pub const FUZZY_SHORT_QUERY_MAX_LEN: usize = 4;
+
 // UI constants
 pub const PANEL_CORNER_RADIUS: f64 = 12.0;
+// Inset of the traffic-light window controls from the top-left corner of the
+// Settings/Help windows' custom overlay titlebar (macOS only).
+pub const TITLEBAR_TRAFFIC_LIGHTS_INSET_X: f64 = 12.0;
+pub const TITLEBAR_TRAFFIC_LIGHTS_INSET_Y: f64 = 12.0;
+
+// Category browsing: synthetic category names layered on top of `EmojiData::category`
+pub const FREQUENTLY_USED_CATEGORY: &str = "Frequently Used";
+pub const RECENT_CATEGORY: &str = "Recent";
+
+// Frecency ranking: recent-use timestamps kept per emoji, and the age-bucketed
+// weight (seconds -> score) each one contributes to that emoji's frecency score.
+pub const FRECENCY_MAX_RECENT_USES: usize = 20;
+pub const FRECENCY_WEIGHT_4H: u64 = 100;
+pub const FRECENCY_WEIGHT_1D: u64 = 70;
+pub const FRECENCY_WEIGHT_1WK: u64 = 50;
+pub const FRECENCY_WEIGHT_1MO: u64 = 30;
+pub const FRECENCY_WEIGHT_STALE: u64 = 10;
+pub const FRECENCY_AGE_4H_SECS: u64 = 4 * 60 * 60;
+pub const FRECENCY_AGE_1D_SECS: u64 = 24 * 60 * 60;
+pub const FRECENCY_AGE_1WK_SECS: u64 = 7 * 24 * 60 * 60;
+pub const FRECENCY_AGE_1MO_SECS: u64 = 30 * 24 * 60 * 60;
+
+// Fitzpatrick skin-tone modifiers (light to dark), appended to a skin-tone-base emoji
+pub const FITZPATRICK_MODIFIERS: [char; 5] =
+    ['\u{1F3FB}', '\u{1F3FC}', '\u{1F3FD}', '\u{1F3FE}', '\u{1F3FF}'];
 
 // File constants
 pub const DEFAULT_EMOJI_FILE: &str = "src/emoji.json";
+// Localized keyword overlay files live next to DEFAULT_EMOJI_FILE, named
+// `keywords.<lang>.json` (e.g. `keywords.es.json`).
 pub const DEFAULT_RANKS_FILE: &str = "ranks.json";
 pub const DEFAULT_SETTINGS_FILE: &str = "settings.json";
+pub const DEFAULT_WINDOW_STATE_FILE: &str = "window-state.json";
+// User-editable overlay file and pack directory, merged on top of the bundled
+// emoji set (see `EmojiManager::with_custom_sources`).
+pub const DEFAULT_CUSTOM_EMOJI_FILE: &str = "custom-emoji.json";
+pub const DEFAULT_EMOJI_PACKS_DIR: &str = "emoji-packs";
 
 // Settings defaults and limits
 pub const DEFAULT_GLOBAL_HOTKEY: &str = "Cmd+Option+Space";
@@ -28,6 +70,14 @@ pub const DEFAULT_WINDOW_WIDTH: f64 = 338.0;
 pub const DEFAULT_WINDOW_HEIGHT: f64 = 290.0;
 pub const DEFAULT_MAX_TOP_EMOJIS: usize = 10;
 pub const DEFAULT_SCALE_FACTOR: f64 = 1.0;
+pub const DEFAULT_START_ON_LOGIN: bool = false;
+pub const DEFAULT_VISIBLE_ON_ALL_WORKSPACES: bool = true;
+// 0 means disabled; the panel stays open until dismissed like today.
+pub const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 0;
+pub const MAX_IDLE_TIMEOUT_SECS: u64 = 300;
+// Opt-in: auto-paste into the previously focused app once it regains focus,
+// for `CopyOnly` mode (see `Settings::auto_paste`).
+pub const DEFAULT_AUTO_PASTE: bool = false;
 
 // Settings window dimensions
 pub const SETTINGS_WINDOW_WIDTH: f64 = 400.0;
@@ -1,15 +1,16 @@
 use crate::constants::*;
 use crate::errors::{EmojiError, LockResultExt};
+use crate::settings::{SearchLanguage, SkinTone};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
     Arc, Mutex, RwLock,
 };
 use std::thread;
-use std::time::Instant;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 /// Emoji data structure matching the JSON format
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -21,13 +22,94 @@ pub struct EmojiData {
     pub tags: Option<Vec<String>>,
     pub unicode_version: Option<String>,
     pub ios_version: Option<String>,
+    /// Whether this emoji accepts a Fitzpatrick skin-tone modifier (e.g. a hand or
+    /// person gesture). Variant emoji themselves are not separate entries, so this
+    /// is false for them even though they share the base's keywords.
+    #[serde(default)]
+    pub skin_tone_base: bool,
+    /// The five Fitzpatrick variants (light to dark), filled in at load time for
+    /// `skin_tone_base` emoji that don't already carry an explicit list in
+    /// `emoji.json`. `None` for emoji that aren't a skin-tone base.
+    #[serde(default)]
+    pub variants: Option<Vec<String>>,
+}
+
+/// Usage record backing frecency ranking: a running count plus a capped ring
+/// of recent-use timestamps (unix seconds), newest last.
+///
+/// Deserializes from either the current object form or a legacy bare integer
+/// (a pre-frecency `ranks.json` count), so existing rank files keep working;
+/// legacy entries just have no recency info until they're used again.
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+pub struct UsageRecord {
+    pub count: u32,
+    #[serde(default)]
+    pub recent_uses: Vec<u64>,
+}
+
+impl<'de> Deserialize<'de> for UsageRecord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            LegacyCount(u32),
+            Record {
+                count: u32,
+                #[serde(default)]
+                recent_uses: Vec<u64>,
+            },
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::LegacyCount(count) => UsageRecord {
+                count,
+                recent_uses: Vec::new(),
+            },
+            Raw::Record { count, recent_uses } => UsageRecord { count, recent_uses },
+        })
+    }
+}
+
+/// Bucketed age -> frecency weight, following the standard frecency
+/// heuristic: very recent uses count far more
+/// than stale ones, so a "used daily this week" emoji outranks one "used a lot
+/// a year ago".
+fn frecency_weight(age_secs: u64) -> u64 {
+    if age_secs < FRECENCY_AGE_4H_SECS {
+        FRECENCY_WEIGHT_4H
+    } else if age_secs < FRECENCY_AGE_1D_SECS {
+        FRECENCY_WEIGHT_1D
+    } else if age_secs < FRECENCY_AGE_1WK_SECS {
+        FRECENCY_WEIGHT_1WK
+    } else if age_secs < FRECENCY_AGE_1MO_SECS {
+        FRECENCY_WEIGHT_1MO
+    } else {
+        FRECENCY_WEIGHT_STALE
+    }
+}
+
+/// Frecency score for a usage record at `now`. Legacy entries with no
+/// recency info (a bare integer in `ranks.json`) fall back to their raw count
+/// so they don't immediately sink to the bottom of the list.
+fn frecency_score(record: &UsageRecord, now: u64) -> u64 {
+    if record.recent_uses.is_empty() {
+        return record.count as u64;
+    }
+    record
+        .recent_uses
+        .iter()
+        .map(|&ts| frecency_weight(now.saturating_sub(ts)))
+        .sum()
 }
 
 /// Data structure for emoji manager
 #[derive(Debug, Default)]
 pub struct EmojiManagerData {
     pub emojis: Vec<EmojiData>,
-    pub ranks: HashMap<String, u32>,
+    pub ranks: HashMap<String, UsageRecord>,
     pub keywords: HashMap<String, Arc<Vec<String>>>,
     pub index: HashMap<String, Vec<usize>>,
     // Loading flags
@@ -35,6 +117,16 @@ pub struct EmojiManagerData {
     pub ranks_loaded: bool,
     pub keywords_built: bool,
     pub index_built: bool,
+
+    // Active search language and its loaded keyword overlay (emoji -> extra keywords),
+    // merged into `keywords` on top of the English description/aliases/tags.
+    pub active_language: SearchLanguage,
+    pub localized_keywords: HashMap<String, Vec<String>>,
+
+    // User's preferred skin tone, applied to skin-tone-base emoji in results, and
+    // the set of base emoji it applies to (populated alongside `variants`).
+    pub default_skin_tone: SkinTone,
+    pub skin_tone_bases: HashSet<String>,
 }
 
 /// Thread-safe emoji manager with caching and efficient search
@@ -43,6 +135,12 @@ pub struct EmojiManager {
     pub emoji_file_path: PathBuf,
     pub ranks_file_path: PathBuf,
 
+    // User-editable custom sources, merged on top of `emoji_file_path` at load time
+    // and on demand via `reload_packs`. Both are optional; a missing file/dir is
+    // silently skipped, same as a missing ranks.json.
+    custom_overlay_path: Option<PathBuf>,
+    packs_dir: Option<PathBuf>,
+
     // Consolidated data storage with RwLock for better read performance
     pub data: Arc<RwLock<EmojiManagerData>>,
 
@@ -62,6 +160,52 @@ fn strip_variation_selector(s: &str) -> String {
     s.chars().filter(|&c| c != '\u{FE0F}').collect()
 }
 
+/// Expand a skin-tone-base emoji into its five Fitzpatrick variants (light to dark).
+fn expand_skin_tone_variants(base: &str) -> Vec<String> {
+    FITZPATRICK_MODIFIERS
+        .iter()
+        .map(|&modifier| format!("{}{}", base, modifier))
+        .collect()
+}
+
+/// Classic Levenshtein edit distance, returning `None` once it's clear the
+/// result would exceed `max_distance` - used to bound the fuzzy fallback's
+/// cost instead of computing a full distance for every candidate keyword.
+fn bounded_levenshtein(a: &str, b: &str, max_distance: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    if a.len().abs_diff(b.len()) > max_distance {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    let distance = prev[b.len()];
+    (distance <= max_distance).then_some(distance)
+}
+
+/// Strip a single trailing Fitzpatrick modifier from an emoji string, if present,
+/// so a variant the user is hovering/long-pressing resolves back to its base.
+fn strip_skin_tone_modifier(emoji: &str) -> &str {
+    match emoji.chars().next_back() {
+        Some(last) if FITZPATRICK_MODIFIERS.contains(&last) => {
+            &emoji[..emoji.len() - last.len_utf8()]
+        }
+        _ => emoji,
+    }
+}
+
 impl Default for EmojiManager {
     fn default() -> Self {
         let home_dir = dirs::home_dir().unwrap_or_else(|| PathBuf::from("."));
@@ -76,6 +220,8 @@ impl EmojiManager {
         Self {
             emoji_file_path,
             ranks_file_path,
+            custom_overlay_path: None,
+            packs_dir: None,
             data: Arc::new(RwLock::new(EmojiManagerData::default())),
             pending_writes: Arc::new(Mutex::new(false)),
             last_write_time: Arc::new(Mutex::new(Instant::now())),
@@ -86,6 +232,18 @@ impl EmojiManager {
         }
     }
 
+    /// Set the user-editable overlay file and/or pack directory merged on top of
+    /// the bundled emoji set. Call before `initialize()`.
+    pub fn with_custom_sources(
+        mut self,
+        custom_overlay_path: Option<PathBuf>,
+        packs_dir: Option<PathBuf>,
+    ) -> Self {
+        self.custom_overlay_path = custom_overlay_path;
+        self.packs_dir = packs_dir;
+        self
+    }
+
     /// Initialize all data structures at startup (retryable on failure)
     pub fn initialize(&self) -> Result<(), EmojiError> {
         if self.init_success.load(Ordering::Acquire) {
@@ -101,6 +259,7 @@ impl EmojiManager {
 
         self.load_emojis()?;
         self.load_ranks()?;
+        self.compute_skin_tone_variants()?;
         self.build_keywords()?;
         self.build_index()?;
 
@@ -109,16 +268,10 @@ impl EmojiManager {
         Ok(())
     }
 
-    /// Load emoji data from JSON file
-    pub fn load_emojis(&self) -> Result<(), EmojiError> {
-        // Check if already loaded (read lock is cheaper)
-        {
-            let data = self.data.read().map_lock_err()?;
-            if data.emojis_loaded {
-                return Ok(());
-            }
-        }
-
+    /// Parse the bundled emoji JSON (embedded in release builds, from disk in
+    /// dev) without consulting the `emojis_loaded` cache flag, so pack merging
+    /// and reload can always start from a pristine base set.
+    fn read_base_emojis(&self) -> Result<Vec<EmojiData>, EmojiError> {
         // Use embedded emoji data for production builds, fallback to file system for development
         let content = if cfg!(debug_assertions) {
             // Development: try to read from file system first, fallback to embedded
@@ -134,7 +287,102 @@ impl EmojiManager {
             include_str!("emoji.json").to_string()
         };
 
-        let emoji_data: Vec<EmojiData> = serde_json::from_str(&content)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Merge one pack's entries into `emojis`: new emoji are appended, and
+    /// entries that match an existing emoji (by the `emoji` field) have their
+    /// `aliases`/`tags` extended instead of being duplicated.
+    fn merge_pack(emojis: &mut Vec<EmojiData>, pack: Vec<EmojiData>) {
+        let mut index_of: HashMap<String, usize> = emojis
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.emoji.clone(), i))
+            .collect();
+
+        for entry in pack {
+            if let Some(&idx) = index_of.get(&entry.emoji) {
+                let existing = &mut emojis[idx];
+                if let Some(extra_aliases) = entry.aliases {
+                    let aliases = existing.aliases.get_or_insert_with(Vec::new);
+                    for alias in extra_aliases {
+                        if !aliases.contains(&alias) {
+                            aliases.push(alias);
+                        }
+                    }
+                }
+                if let Some(extra_tags) = entry.tags {
+                    let tags = existing.tags.get_or_insert_with(Vec::new);
+                    for tag in extra_tags {
+                        if !tags.contains(&tag) {
+                            tags.push(tag);
+                        }
+                    }
+                }
+            } else {
+                index_of.insert(entry.emoji.clone(), emojis.len());
+                emojis.push(entry);
+            }
+        }
+    }
+
+    /// Load the user-editable overlay file and any pack JSONs in `packs_dir`
+    /// (read in filename order, so later packs can extend entries added by
+    /// earlier ones), merging their entries into `base`. A missing overlay file
+    /// or pack directory is silently skipped.
+    fn load_custom_sources(&self, base: &mut Vec<EmojiData>) {
+        if let Some(overlay_path) = &self.custom_overlay_path {
+            if let Ok(content) = fs::read_to_string(overlay_path) {
+                match serde_json::from_str::<Vec<EmojiData>>(&content) {
+                    Ok(pack) => Self::merge_pack(base, pack),
+                    Err(e) => log::warn!(
+                        "Failed to parse custom emoji overlay {}: {}",
+                        overlay_path.display(),
+                        e
+                    ),
+                }
+            }
+        }
+
+        let Some(packs_dir) = &self.packs_dir else {
+            return;
+        };
+        let Ok(entries) = fs::read_dir(packs_dir) else {
+            return;
+        };
+
+        let mut pack_paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+            .collect();
+        pack_paths.sort();
+
+        for path in pack_paths {
+            match fs::read_to_string(&path) {
+                Ok(content) => match serde_json::from_str::<Vec<EmojiData>>(&content) {
+                    Ok(pack) => Self::merge_pack(base, pack),
+                    Err(e) => {
+                        log::warn!("Failed to parse emoji pack {}: {}", path.display(), e)
+                    }
+                },
+                Err(e) => log::warn!("Failed to read emoji pack {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    /// Load emoji data from JSON file, merged with any custom overlay/packs
+    pub fn load_emojis(&self) -> Result<(), EmojiError> {
+        // Check if already loaded (read lock is cheaper)
+        {
+            let data = self.data.read().map_lock_err()?;
+            if data.emojis_loaded {
+                return Ok(());
+            }
+        }
+
+        let mut emoji_data = self.read_base_emojis()?;
+        self.load_custom_sources(&mut emoji_data);
 
         // Update with write lock
         {
@@ -147,6 +395,27 @@ impl EmojiManager {
         Ok(())
     }
 
+    /// Re-read the custom overlay file and pack directory on top of a fresh copy
+    /// of the bundled set, and rebuild `keywords`/`index` across the merged
+    /// result, so users can extend search with new packs without restarting.
+    pub fn reload_packs(&self) -> Result<(), EmojiError> {
+        let mut emoji_data = self.read_base_emojis()?;
+        self.load_custom_sources(&mut emoji_data);
+
+        {
+            let mut data = self.data.write().map_lock_err()?;
+            data.emojis = emoji_data;
+            data.keywords_built = false;
+            data.index_built = false;
+        }
+
+        self.compute_skin_tone_variants()?;
+        self.build_keywords()?;
+        self.build_index()?;
+        log::info!("Reloaded custom emoji packs");
+        Ok(())
+    }
+
     /// Load usage ranks from file
     pub fn load_ranks(&self) -> Result<(), EmojiError> {
         // Check if already loaded (read lock is cheaper)
@@ -230,6 +499,18 @@ impl EmojiManager {
                     }
                 }
 
+                // Merge in the active language's keyword overlay, if any, so
+                // e.g. "gato"/"chat" match alongside the English keywords above.
+                if let Some(localized) = data.localized_keywords.get(emoji) {
+                    for keyword in localized {
+                        let keyword = keyword.to_lowercase().replace('_', " ");
+                        if !seen.contains(&keyword) {
+                            keywords.push(keyword.clone());
+                            seen.insert(keyword);
+                        }
+                    }
+                }
+
                 // Use Arc to avoid cloning when accessing keywords
                 keywords_map.insert(emoji.clone(), Arc::new(keywords));
             }
@@ -326,17 +607,173 @@ impl EmojiManager {
         Ok(())
     }
 
-    /// Get top emojis from ranks data
-    fn get_top_emojis_from_ranks(&self, ranks: &HashMap<String, u32>, limit: usize) -> Vec<String> {
+    /// Expand skin-tone-base emoji (e.g. a hand gesture) into their five Fitzpatrick
+    /// variants, filling in `variants` for entries that don't already carry an
+    /// explicit list from `emoji.json`. The variants aren't separate index entries;
+    /// they inherit the base's keywords and are substituted in at query time by
+    /// `apply_skin_tone_preference`.
+    fn compute_skin_tone_variants(&self) -> Result<(), EmojiError> {
+        let mut data = self.data.write().map_lock_err()?;
+        let mut skin_tone_bases = HashSet::new();
+
+        for emoji_data in data.emojis.iter_mut() {
+            if !emoji_data.skin_tone_base {
+                continue;
+            }
+            if emoji_data.variants.is_none() {
+                emoji_data.variants = Some(expand_skin_tone_variants(&emoji_data.emoji));
+            }
+            skin_tone_bases.insert(emoji_data.emoji.clone());
+        }
+
+        data.skin_tone_bases = skin_tone_bases;
+        Ok(())
+    }
+
+    /// Set the user's preferred skin tone, applied to skin-tone-base emoji
+    /// wherever they appear in subsequent `get_emojis`/category results.
+    pub fn set_default_skin_tone(&self, tone: SkinTone) -> Result<(), EmojiError> {
+        let mut data = self.data.write().map_lock_err()?;
+        data.default_skin_tone = tone;
+        Ok(())
+    }
+
+    /// Substitute the user's preferred tone variant for each skin-tone-base emoji
+    /// in `emojis`, leaving everything else untouched.
+    fn apply_skin_tone_preference(&self, emojis: Vec<String>) -> Result<Vec<String>, EmojiError> {
+        let data = self.data.read().map_lock_err()?;
+        let Some(modifier) = data.default_skin_tone.modifier() else {
+            return Ok(emojis);
+        };
+
+        Ok(emojis
+            .into_iter()
+            .map(|emoji| {
+                if data.skin_tone_bases.contains(&emoji) {
+                    format!("{}{}", emoji, modifier)
+                } else {
+                    emoji
+                }
+            })
+            .collect())
+    }
+
+    /// Get the full list of Fitzpatrick variants for a skin-tone-base emoji, so
+    /// the panel can show a long-press/hover variant popup. `emoji` may be the
+    /// base itself or one of its variants.
+    pub fn get_variants(&self, emoji: &str) -> Result<Vec<String>, EmojiError> {
+        let base = strip_skin_tone_modifier(emoji);
+        let data = self.data.read().map_lock_err()?;
+
+        let variants = data
+            .emojis
+            .iter()
+            .find(|e| e.emoji == base)
+            .and_then(|e| e.variants.clone())
+            .unwrap_or_default();
+        Ok(variants)
+    }
+
+    /// Load the keyword overlay file for a language (e.g. `keywords.es.json`), a
+    /// JSON object mapping emoji to extra keyword lists. A missing file just
+    /// means that language has no overlay yet; English keywords still apply.
+    fn load_localized_keywords(&self, language: SearchLanguage) -> HashMap<String, Vec<String>> {
+        if language == SearchLanguage::En {
+            // English keywords live directly on EmojiData; no overlay needed.
+            return HashMap::new();
+        }
+
+        let dir = self.emoji_file_path.parent().unwrap_or_else(|| Path::new("."));
+        let path = dir.join(format!("keywords.{}.json", language.file_suffix()));
+
+        // Use embedded keyword data for production builds, fallback to file system for development
+        let content = if cfg!(debug_assertions) {
+            // Development: try to read from file system first, fallback to embedded
+            match fs::read_to_string(&path) {
+                Ok(content) => Some(content),
+                Err(_) => {
+                    log::info!(
+                        "Could not read {} from filesystem, using embedded data",
+                        path.display()
+                    );
+                    Self::embedded_keywords(language).map(|s| s.to_string())
+                }
+            }
+        } else {
+            // Production: always use embedded data
+            Self::embedded_keywords(language).map(|s| s.to_string())
+        };
+
+        match content {
+            Some(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+                log::warn!("Failed to parse {}: {}", path.display(), e);
+                HashMap::new()
+            }),
+            None => {
+                log::debug!("No localized keyword file for {:?}", language);
+                HashMap::new()
+            }
+        }
+    }
+
+    /// Keyword overlay JSON bundled into the binary, one per supported
+    /// non-English `SearchLanguage`, mirroring `read_base_emojis`'s embedding
+    /// of `emoji.json` so localized search still works in a packaged build
+    /// with no `src/` directory next to the binary.
+    fn embedded_keywords(language: SearchLanguage) -> Option<&'static str> {
+        match language {
+            SearchLanguage::En => None,
+            SearchLanguage::Es => Some(include_str!("keywords.es.json")),
+            SearchLanguage::De => Some(include_str!("keywords.de.json")),
+            SearchLanguage::Fr => Some(include_str!("keywords.fr.json")),
+            SearchLanguage::Zh => Some(include_str!("keywords.zh.json")),
+            SearchLanguage::Ja => Some(include_str!("keywords.ja.json")),
+        }
+    }
+
+    /// Switch the active search language, reloading its keyword overlay (if
+    /// any) and rebuilding `keywords`/`index` so `get_emojis` matches the new
+    /// locale's keywords, still falling back to the English ones.
+    pub fn set_search_language(&self, language: SearchLanguage) -> Result<(), EmojiError> {
+        let localized_keywords = self.load_localized_keywords(language);
+
+        {
+            let mut data = self.data.write().map_lock_err()?;
+            data.active_language = language;
+            data.localized_keywords = localized_keywords;
+            data.keywords_built = false;
+            data.index_built = false;
+        }
+
+        self.build_keywords()?;
+        self.build_index()?;
+        Ok(())
+    }
+
+    /// Get top emojis from ranks data, ordered by frecency score (recent and
+    /// frequent use), not raw usage count.
+    fn get_top_emojis_from_ranks(
+        &self,
+        ranks: &HashMap<String, UsageRecord>,
+        limit: usize,
+    ) -> Vec<String> {
         if ranks.is_empty() {
             log::debug!("No ranks found");
             return Vec::new();
         }
 
-        let mut emoji_ranks: Vec<(&String, &u32)> = ranks.iter().collect();
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let mut emoji_ranks: Vec<(&String, u64)> = ranks
+            .iter()
+            .map(|(emoji, record)| (emoji, frecency_score(record, now)))
+            .collect();
 
-        // Sort by count in descending order (highest usage first)
-        emoji_ranks.sort_by_key(|(_, &count)| std::cmp::Reverse(count));
+        // Sort by frecency score in descending order (highest first)
+        emoji_ranks.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
 
         emoji_ranks
             .into_iter()
@@ -474,6 +911,73 @@ impl EmojiManager {
         });
     }
 
+    /// Typo-tolerant fallback for `get_emojis`, invoked only when the exact/prefix
+    /// index yields too few hits. Limits comparisons to keywords indexed under a
+    /// first-two-character bucket sharing the query's first letter (already built
+    /// in `index`), then keeps keywords within a bounded edit distance - 1 for
+    /// short queries, 2 for longer ones, since typos matter more proportionally
+    /// on a short query. `already_matched` emoji are skipped so results don't
+    /// duplicate the exact hits they're appended to.
+    fn fuzzy_search(
+        &self,
+        filter_word: &str,
+        already_matched: &[String],
+    ) -> Result<Vec<String>, EmojiError> {
+        let data = self.data.read().map_lock_err()?;
+
+        let Some(first_char) = filter_word.chars().next() else {
+            return Ok(Vec::new());
+        };
+        let max_distance = if filter_word.chars().count() <= FUZZY_SHORT_QUERY_MAX_LEN {
+            1
+        } else {
+            2
+        };
+
+        let mut candidate_indices: HashSet<usize> = HashSet::new();
+        for (key, indices) in data.index.iter() {
+            if key.chars().count() == 2 && key.starts_with(first_char) {
+                candidate_indices.extend(indices.iter().copied());
+            }
+        }
+
+        let already: HashSet<&String> = already_matched.iter().collect();
+        let mut hits: Vec<(String, usize)> = Vec::new();
+
+        for idx in candidate_indices {
+            let Some(emoji_data) = data.emojis.get(idx) else {
+                continue;
+            };
+            if already.contains(&emoji_data.emoji) {
+                continue;
+            }
+            let Some(keywords) = data.keywords.get(&emoji_data.emoji) else {
+                continue;
+            };
+
+            let best_distance = keywords
+                .iter()
+                .filter(|k| k.len() >= MIN_KEYWORD_LENGTH)
+                .filter_map(|k| bounded_levenshtein(filter_word, k, max_distance))
+                .min();
+
+            if let Some(distance) = best_distance {
+                hits.push((emoji_data.emoji.clone(), distance));
+            }
+        }
+
+        // Below exact matches (the caller appends these after its own list), then
+        // by edit distance; frecency is applied uniformly afterwards by
+        // `order_emojis_by_usage` over the combined result.
+        hits.sort_by_key(|(_, distance)| *distance);
+
+        Ok(hits
+            .into_iter()
+            .take(MAX_SEARCH_RESULTS)
+            .map(|(emoji, _)| emoji)
+            .collect())
+    }
+
     /// Get filtered emojis as array with optimized memory usage and result limits
     pub fn get_emojis(
         &self,
@@ -499,6 +1003,15 @@ impl EmojiManager {
             }
         }
 
+        // A `:shortcode:` token resolves straight to its emoji via the
+        // compile-time phf table, independent of whatever aliases ship in
+        // emoji.json.
+        if let Some(name) = original.strip_prefix(':').and_then(|s| s.strip_suffix(':')) {
+            if let Some(emoji) = crate::shortcodes::resolve(&name.to_lowercase()) {
+                return Ok(vec![emoji.to_string()]);
+            }
+        }
+
         let filter_word = original.to_lowercase();
 
         let emoji_list: Vec<String> = if filter_word.len() < MIN_SEARCH_LENGTH {
@@ -514,18 +1027,39 @@ impl EmojiManager {
         } else {
             log::debug!("Getting emojis for filter word: '{}'", filter_word);
             // Index is already built at startup, now using emoji indices
-            let data = self.data.read().map_lock_err()?;
+            let mut matches: Vec<String> = {
+                let data = self.data.read().map_lock_err()?;
+
+                if let Some(emoji_indices) = data.index.get(&filter_word) {
+                    emoji_indices
+                        .iter()
+                        .take(MAX_SEARCH_RESULTS) // Limit results for better performance
+                        .filter_map(|&idx| data.emojis.get(idx))
+                        .map(|emoji_data| emoji_data.emoji.clone())
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            };
 
-            if let Some(emoji_indices) = data.index.get(&filter_word) {
-                emoji_indices
-                    .iter()
-                    .take(MAX_SEARCH_RESULTS) // Limit results for better performance
-                    .filter_map(|&idx| data.emojis.get(idx))
-                    .map(|emoji_data| emoji_data.emoji.clone())
-                    .collect()
-            } else {
-                Vec::new()
+            // Bare shortcode fallback (e.g. "wave" without colons) when the
+            // index has nothing for it, e.g. a shortcode not present in
+            // emoji.json's own aliases.
+            if matches.is_empty() {
+                if let Some(emoji) = crate::shortcodes::resolve(&filter_word) {
+                    matches.push(emoji.to_string());
+                }
+            }
+
+            // Typo-tolerant fallback: only kicks in when exact/prefix hits are
+            // sparse (fewer than FUZZY_MATCH_TRIGGER_RESULTS), so queries with
+            // enough hits stay on the fast path above.
+            if matches.len() < FUZZY_MATCH_TRIGGER_RESULTS {
+                let fuzzy_matches = self.fuzzy_search(&filter_word, &matches)?;
+                matches.extend(fuzzy_matches);
             }
+
+            matches
         };
 
         // Order emojis by usage frequency (skip if max_top_emojis is 0)
@@ -535,10 +1069,74 @@ impl EmojiManager {
             self.order_emojis_by_usage(emoji_list, max_top_emojis)
         };
 
+        let ordered_emojis = self.apply_skin_tone_preference(ordered_emojis)?;
+
         log::debug!("Returning {} emojis", ordered_emojis.len());
         Ok(ordered_emojis)
     }
 
+    /// List categories for tabbed browsing, in first-seen order, preceded by
+    /// the synthetic "Frequently Used" category (when any usage is recorded)
+    /// and "Recent" (when any recorded use has a timestamp).
+    pub fn get_categories(&self) -> Result<Vec<String>, EmojiError> {
+        let data = self.data.read().map_lock_err()?;
+
+        let mut categories = Vec::new();
+        if !data.ranks.is_empty() {
+            categories.push(FREQUENTLY_USED_CATEGORY.to_string());
+        }
+        if data.ranks.values().any(|r| !r.recent_uses.is_empty()) {
+            categories.push(RECENT_CATEGORY.to_string());
+        }
+
+        let mut seen: HashSet<&str> = HashSet::new();
+        for emoji_data in data.emojis.iter() {
+            if let Some(category) = emoji_data.category.as_deref() {
+                if seen.insert(category) {
+                    categories.push(category.to_string());
+                }
+            }
+        }
+
+        Ok(categories)
+    }
+
+    /// Get up to `max` emojis for a category tab. `category` may be a real
+    /// `EmojiData::category` value, or the synthetic "Frequently Used"/"Recent".
+    pub fn get_emojis_by_category(
+        &self,
+        category: &str,
+        max: usize,
+    ) -> Result<Vec<String>, EmojiError> {
+        let data = self.data.read().map_lock_err()?;
+
+        let emojis = if category == FREQUENTLY_USED_CATEGORY {
+            self.get_top_emojis_from_ranks(&data.ranks, max)
+        } else if category == RECENT_CATEGORY {
+            let mut by_recency: Vec<(&String, u64)> = data
+                .ranks
+                .iter()
+                .filter_map(|(emoji, record)| record.recent_uses.last().map(|&ts| (emoji, ts)))
+                .collect();
+            by_recency.sort_by_key(|(_, ts)| std::cmp::Reverse(*ts));
+            by_recency
+                .into_iter()
+                .take(max)
+                .map(|(emoji, _)| emoji.clone())
+                .collect()
+        } else {
+            data.emojis
+                .iter()
+                .filter(|e| e.category.as_deref() == Some(category))
+                .take(max)
+                .map(|e| e.emoji.clone())
+                .collect()
+        };
+
+        drop(data);
+        self.apply_skin_tone_preference(emojis)
+    }
+
     /// Get keywords for an emoji as array
     pub fn get_keywords(&self, emoji: &str) -> Result<Vec<String>, EmojiError> {
         // Keywords are already built at startup
@@ -558,11 +1156,21 @@ impl EmojiManager {
         let amount = amount.unwrap_or(1);
         log::debug!("Incrementing usage for emoji: '{}' by {}", emoji, amount);
 
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
         // Ranks are already loaded at startup
         {
             let mut data = self.data.write().map_lock_err()?;
-            let count = data.ranks.entry(emoji.to_string()).or_insert(0);
-            *count += amount;
+            let record = data.ranks.entry(emoji.to_string()).or_default();
+            record.count += amount;
+            record.recent_uses.push(now);
+            if record.recent_uses.len() > FRECENCY_MAX_RECENT_USES {
+                let overflow = record.recent_uses.len() - FRECENCY_MAX_RECENT_USES;
+                record.recent_uses.drain(0..overflow);
+            }
         }
 
         // Schedule batched write
@@ -602,7 +1210,7 @@ impl EmojiManager {
         }
 
         // Write empty ranks to file immediately
-        let ranks_data: HashMap<String, u32> = HashMap::new();
+        let ranks_data: HashMap<String, UsageRecord> = HashMap::new();
         let json_content = serde_json::to_string(&ranks_data)?;
         fs::write(&self.ranks_file_path, json_content)?;
 
@@ -610,3 +1218,73 @@ impl EmojiManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frecency_weight_buckets() {
+        assert_eq!(frecency_weight(0), FRECENCY_WEIGHT_4H);
+        assert_eq!(frecency_weight(FRECENCY_AGE_4H_SECS - 1), FRECENCY_WEIGHT_4H);
+        assert_eq!(frecency_weight(FRECENCY_AGE_4H_SECS), FRECENCY_WEIGHT_1D);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1D_SECS - 1), FRECENCY_WEIGHT_1D);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1D_SECS), FRECENCY_WEIGHT_1WK);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1WK_SECS - 1), FRECENCY_WEIGHT_1WK);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1WK_SECS), FRECENCY_WEIGHT_1MO);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1MO_SECS - 1), FRECENCY_WEIGHT_1MO);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1MO_SECS), FRECENCY_WEIGHT_STALE);
+        assert_eq!(frecency_weight(FRECENCY_AGE_1MO_SECS * 10), FRECENCY_WEIGHT_STALE);
+    }
+
+    #[test]
+    fn test_frecency_score_sums_weights_of_each_use() {
+        let now = 1_000_000u64;
+        let record = UsageRecord {
+            count: 2,
+            recent_uses: vec![now, now - FRECENCY_AGE_1D_SECS],
+        };
+        // One use inside the 4h bucket, one exactly a day old, which falls in
+        // the 1wk bucket.
+        assert_eq!(
+            frecency_score(&record, now),
+            FRECENCY_WEIGHT_4H + FRECENCY_WEIGHT_1WK
+        );
+    }
+
+    #[test]
+    fn test_frecency_score_legacy_record_falls_back_to_count() {
+        // A bare-integer `ranks.json` entry deserializes with no `recent_uses`;
+        // until it's used again it should rank by raw count, not vanish to 0.
+        let record = UsageRecord {
+            count: 42,
+            recent_uses: Vec::new(),
+        };
+        assert_eq!(frecency_score(&record, 1_000_000), 42);
+    }
+
+    #[test]
+    fn test_usage_record_deserializes_legacy_bare_integer() {
+        let record: UsageRecord = serde_json::from_str("7").unwrap();
+        assert_eq!(
+            record,
+            UsageRecord {
+                count: 7,
+                recent_uses: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_usage_record_deserializes_object_form() {
+        let record: UsageRecord =
+            serde_json::from_str(r#"{"count": 3, "recent_uses": [100, 200]}"#).unwrap();
+        assert_eq!(
+            record,
+            UsageRecord {
+                count: 3,
+                recent_uses: vec![100, 200],
+            }
+        );
+    }
+}
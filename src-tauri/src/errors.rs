@@ -36,6 +36,9 @@ pub enum EmojiError {
 
     #[error("Tauri error: {0}")]
     Tauri(String),
+
+    #[error("Hotkey conflict: {0}")]
+    HotkeyConflict(String),
 }
 
 impl From<EmojiError> for String {
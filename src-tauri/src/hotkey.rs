@@ -13,10 +13,19 @@ pub fn parse_hotkey(hotkey_str: &str) -> Result<Shortcut, String> {
     for part in parts {
         let part = part.trim();
         match part {
-            "Cmd" | "Command" | "Super" => modifiers |= Modifiers::SUPER,
-            "Ctrl" | "Control" => modifiers |= Modifiers::CONTROL,
-            "Option" | "Alt" => modifiers |= Modifiers::ALT,
-            "Shift" => modifiers |= Modifiers::SHIFT,
+            // `Modifiers` is a logical (side-less) bitflag, so e.g. `LeftCmd`
+            // and `RightCmd` both just set `Modifiers::SUPER` - mirrors how
+            // winit/alacritty treat Option/Alt as interchangeable.
+            "Cmd" | "Command" | "Super" | "LeftCmd" | "RightCmd" | "LeftSuper" | "RightSuper" => {
+                modifiers |= Modifiers::SUPER
+            }
+            "Ctrl" | "Control" | "LeftCtrl" | "RightCtrl" | "LeftControl" | "RightControl" => {
+                modifiers |= Modifiers::CONTROL
+            }
+            "Option" | "Alt" | "LeftOption" | "RightOption" | "LeftAlt" | "RightAlt" => {
+                modifiers |= Modifiers::ALT
+            }
+            "Shift" | "LeftShift" | "RightShift" => modifiers |= Modifiers::SHIFT,
             // Parse the key code
             key => {
                 if key_code.is_some() {
@@ -39,6 +48,180 @@ pub fn parse_hotkey(hotkey_str: &str) -> Result<Shortcut, String> {
     Ok(Shortcut::new(modifiers, key_code))
 }
 
+/// Render a parsed shortcut back to a stable, platform-aware display string,
+/// the inverse of `parse_hotkey` (e.g. `Cmd+Shift+Space` on macOS,
+/// `Super+Shift+Space` elsewhere for the same underlying modifier). Modifiers
+/// are always emitted in the same fixed order so two accelerators that parse
+/// to the same `Shortcut` also format identically.
+pub fn format_hotkey(shortcut: &Shortcut) -> Result<String, String> {
+    let mods = shortcut.mods();
+    let mut parts = Vec::new();
+
+    if mods.contains(Modifiers::SUPER) {
+        parts.push(if cfg!(target_os = "macos") {
+            "Cmd"
+        } else {
+            "Super"
+        });
+    }
+    if mods.contains(Modifiers::CONTROL) {
+        parts.push("Ctrl");
+    }
+    if mods.contains(Modifiers::ALT) {
+        parts.push(if cfg!(target_os = "macos") {
+            "Option"
+        } else {
+            "Alt"
+        });
+    }
+    if mods.contains(Modifiers::SHIFT) {
+        parts.push("Shift");
+    }
+
+    parts.push(format_key_code(shortcut.key())?);
+
+    Ok(parts.join("+"))
+}
+
+/// Parse a hotkey string and immediately format it back, so the UI can
+/// always display the canonical rendering regardless of how the user typed
+/// the accelerator.
+pub fn normalize_hotkey(hotkey_str: &str) -> Result<String, String> {
+    format_hotkey(&parse_hotkey(hotkey_str)?)
+}
+
+/// Infallible variant of `format_hotkey` for the Settings UI to round-trip a
+/// stored binding to its display form. Falls back to a debug rendering of
+/// the key code for the rare `Code` variant `format_key_code` doesn't have a
+/// label for yet, rather than erroring out of a settings render.
+pub fn shortcut_to_string(shortcut: &Shortcut) -> String {
+    format_hotkey(shortcut).unwrap_or_else(|_| format!("{:?}", shortcut.key()))
+}
+
+/// Render a `Code` back into the label `parse_key_code` accepts for it.
+fn format_key_code(code: Code) -> Result<&'static str, String> {
+    match code {
+        // Letters
+        Code::KeyA => Ok("A"),
+        Code::KeyB => Ok("B"),
+        Code::KeyC => Ok("C"),
+        Code::KeyD => Ok("D"),
+        Code::KeyE => Ok("E"),
+        Code::KeyF => Ok("F"),
+        Code::KeyG => Ok("G"),
+        Code::KeyH => Ok("H"),
+        Code::KeyI => Ok("I"),
+        Code::KeyJ => Ok("J"),
+        Code::KeyK => Ok("K"),
+        Code::KeyL => Ok("L"),
+        Code::KeyM => Ok("M"),
+        Code::KeyN => Ok("N"),
+        Code::KeyO => Ok("O"),
+        Code::KeyP => Ok("P"),
+        Code::KeyQ => Ok("Q"),
+        Code::KeyR => Ok("R"),
+        Code::KeyS => Ok("S"),
+        Code::KeyT => Ok("T"),
+        Code::KeyU => Ok("U"),
+        Code::KeyV => Ok("V"),
+        Code::KeyW => Ok("W"),
+        Code::KeyX => Ok("X"),
+        Code::KeyY => Ok("Y"),
+        Code::KeyZ => Ok("Z"),
+
+        // Numbers
+        Code::Digit0 => Ok("0"),
+        Code::Digit1 => Ok("1"),
+        Code::Digit2 => Ok("2"),
+        Code::Digit3 => Ok("3"),
+        Code::Digit4 => Ok("4"),
+        Code::Digit5 => Ok("5"),
+        Code::Digit6 => Ok("6"),
+        Code::Digit7 => Ok("7"),
+        Code::Digit8 => Ok("8"),
+        Code::Digit9 => Ok("9"),
+
+        // Function keys
+        Code::F1 => Ok("F1"),
+        Code::F2 => Ok("F2"),
+        Code::F3 => Ok("F3"),
+        Code::F4 => Ok("F4"),
+        Code::F5 => Ok("F5"),
+        Code::F6 => Ok("F6"),
+        Code::F7 => Ok("F7"),
+        Code::F8 => Ok("F8"),
+        Code::F9 => Ok("F9"),
+        Code::F10 => Ok("F10"),
+        Code::F11 => Ok("F11"),
+        Code::F12 => Ok("F12"),
+
+        // Special keys
+        Code::Space => Ok("Space"),
+        Code::Enter => Ok("Enter"),
+        Code::Tab => Ok("Tab"),
+        Code::Backspace => Ok("Backspace"),
+        Code::Escape => Ok("Escape"),
+        Code::Delete => Ok("Delete"),
+        Code::Home => Ok("Home"),
+        Code::End => Ok("End"),
+        Code::PageUp => Ok("PageUp"),
+        Code::PageDown => Ok("PageDown"),
+        Code::ArrowUp => Ok("ArrowUp"),
+        Code::ArrowDown => Ok("ArrowDown"),
+        Code::ArrowLeft => Ok("ArrowLeft"),
+        Code::ArrowRight => Ok("ArrowRight"),
+
+        // Punctuation
+        Code::Minus => Ok("Minus"),
+        Code::Equal => Ok("Equal"),
+        Code::BracketLeft => Ok("BracketLeft"),
+        Code::BracketRight => Ok("BracketRight"),
+        Code::Backslash => Ok("Backslash"),
+        Code::Semicolon => Ok("Semicolon"),
+        Code::Quote => Ok("Quote"),
+        Code::Comma => Ok("Comma"),
+        Code::Period => Ok("Period"),
+        Code::Slash => Ok("Slash"),
+        Code::Backquote => Ok("Backquote"),
+
+        // Numpad
+        Code::Numpad0 => Ok("Numpad0"),
+        Code::Numpad1 => Ok("Numpad1"),
+        Code::Numpad2 => Ok("Numpad2"),
+        Code::Numpad3 => Ok("Numpad3"),
+        Code::Numpad4 => Ok("Numpad4"),
+        Code::Numpad5 => Ok("Numpad5"),
+        Code::Numpad6 => Ok("Numpad6"),
+        Code::Numpad7 => Ok("Numpad7"),
+        Code::Numpad8 => Ok("Numpad8"),
+        Code::Numpad9 => Ok("Numpad9"),
+        Code::NumpadAdd => Ok("NumpadAdd"),
+        Code::NumpadSubtract => Ok("NumpadSubtract"),
+        Code::NumpadMultiply => Ok("NumpadMultiply"),
+        Code::NumpadDivide => Ok("NumpadDivide"),
+        Code::NumpadDecimal => Ok("NumpadDecimal"),
+        Code::NumpadEnter => Ok("NumpadEnter"),
+        Code::NumpadEqual => Ok("NumpadEqual"),
+
+        // Media/volume keys
+        Code::MediaPlayPause => Ok("MediaPlayPause"),
+        Code::MediaStop => Ok("MediaStop"),
+        Code::MediaTrackNext => Ok("MediaTrackNext"),
+        Code::MediaTrackPrevious => Ok("MediaTrackPrevious"),
+        Code::AudioVolumeUp => Ok("VolumeUp"),
+        Code::AudioVolumeDown => Ok("VolumeDown"),
+        Code::AudioVolumeMute => Ok("VolumeMute"),
+
+        // Misc
+        Code::CapsLock => Ok("CapsLock"),
+        Code::Insert => Ok("Insert"),
+        Code::PrintScreen => Ok("PrintScreen"),
+        Code::ContextMenu => Ok("ContextMenu"),
+
+        other => Err(format!("Key {:?} can't be represented as a hotkey label", other)),
+    }
+}
+
 /// Parse a key string into a Code
 fn parse_key_code(key: &str) -> Result<Code, String> {
     match key.to_uppercase().as_str() {
@@ -125,6 +308,40 @@ fn parse_key_code(key: &str) -> Result<Code, String> {
         "SLASH" | "/" => Ok(Code::Slash),
         "BACKQUOTE" | "`" => Ok(Code::Backquote),
 
+        // Numpad
+        "NUMPAD0" => Ok(Code::Numpad0),
+        "NUMPAD1" => Ok(Code::Numpad1),
+        "NUMPAD2" => Ok(Code::Numpad2),
+        "NUMPAD3" => Ok(Code::Numpad3),
+        "NUMPAD4" => Ok(Code::Numpad4),
+        "NUMPAD5" => Ok(Code::Numpad5),
+        "NUMPAD6" => Ok(Code::Numpad6),
+        "NUMPAD7" => Ok(Code::Numpad7),
+        "NUMPAD8" => Ok(Code::Numpad8),
+        "NUMPAD9" => Ok(Code::Numpad9),
+        "NUMPADADD" => Ok(Code::NumpadAdd),
+        "NUMPADSUBTRACT" => Ok(Code::NumpadSubtract),
+        "NUMPADMULTIPLY" => Ok(Code::NumpadMultiply),
+        "NUMPADDIVIDE" => Ok(Code::NumpadDivide),
+        "NUMPADDECIMAL" => Ok(Code::NumpadDecimal),
+        "NUMPADENTER" => Ok(Code::NumpadEnter),
+        "NUMPADEQUAL" => Ok(Code::NumpadEqual),
+
+        // Media/volume keys
+        "MEDIAPLAYPAUSE" => Ok(Code::MediaPlayPause),
+        "MEDIASTOP" => Ok(Code::MediaStop),
+        "MEDIATRACKNEXT" => Ok(Code::MediaTrackNext),
+        "MEDIATRACKPREVIOUS" => Ok(Code::MediaTrackPrevious),
+        "VOLUMEUP" | "AUDIOVOLUMEUP" => Ok(Code::AudioVolumeUp),
+        "VOLUMEDOWN" | "AUDIOVOLUMEDOWN" => Ok(Code::AudioVolumeDown),
+        "VOLUMEMUTE" | "AUDIOVOLUMEMUTE" => Ok(Code::AudioVolumeMute),
+
+        // Misc
+        "CAPSLOCK" => Ok(Code::CapsLock),
+        "INSERT" => Ok(Code::Insert),
+        "PRINTSCREEN" => Ok(Code::PrintScreen),
+        "CONTEXTMENU" | "MENU" => Ok(Code::ContextMenu),
+
         _ => Err(format!("Unknown key: {}", key)),
     }
 }
@@ -156,4 +373,49 @@ mod tests {
         let result = parse_hotkey("InvalidKey");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_format_hotkey_modifier_order_is_stable() {
+        // "Shift+Ctrl+A" and "Ctrl+Shift+A" parse to the same Shortcut and
+        // must format identically regardless of input order.
+        let a = format_hotkey(&parse_hotkey("Shift+Ctrl+A").unwrap()).unwrap();
+        let b = format_hotkey(&parse_hotkey("Ctrl+Shift+A").unwrap()).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, "Ctrl+Shift+A");
+    }
+
+    #[test]
+    fn test_normalize_hotkey_round_trips() {
+        let normalized = normalize_hotkey("Cmd+Option+Space").unwrap();
+        assert_eq!(normalize_hotkey(&normalized).unwrap(), normalized);
+    }
+
+    #[test]
+    fn test_left_right_modifiers_collapse_to_same_shortcut() {
+        // `Modifiers` has no side, so Left/Right-qualified tokens are just
+        // accepted aliases for the same flag as their unqualified form.
+        let left = parse_hotkey("LeftCmd+LeftShift+A").unwrap();
+        let right = parse_hotkey("RightSuper+RightShift+A").unwrap();
+        let plain = parse_hotkey("Cmd+Shift+A").unwrap();
+        assert_eq!(left, plain);
+        assert_eq!(right, plain);
+    }
+
+    #[test]
+    fn test_parse_hotkey_shortcut_to_string_round_trips_numpad_media_and_misc() {
+        for hotkey in [
+            "Ctrl+Numpad5",
+            "Ctrl+NumpadEnter",
+            "Cmd+MediaPlayPause",
+            "Shift+VolumeUp",
+            "Ctrl+CapsLock",
+            "Cmd+Insert",
+            "Ctrl+PrintScreen",
+            "Cmd+ContextMenu",
+        ] {
+            let shortcut = parse_hotkey(hotkey).unwrap();
+            let rendered = shortcut_to_string(&shortcut);
+            assert_eq!(parse_hotkey(&rendered).unwrap(), shortcut);
+        }
+    }
 }
@@ -1,3 +1,5 @@
+mod autostart;
+mod cli;
 mod command;
 pub mod constants;
 pub mod emoji_manager;
@@ -6,28 +8,79 @@ mod hotkey;
 mod panel;
 mod permissions;
 mod positioning;
-mod settings;
+pub mod settings;
+mod shortcodes;
 mod tray;
+mod window_state;
 
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64};
 use std::sync::{Arc, Mutex};
 // time utilities not needed here anymore
 use tauri::Manager;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
 
 use crate::emoji_manager::EmojiManager;
-use crate::settings::SettingsManager;
+use crate::settings::{HotkeyAction, SettingsManager};
+use crate::window_state::WindowStateManager;
 
 /// Application state containing shared resources
 #[derive(Debug)]
 pub struct AppState {
     pub emoji_manager: Arc<EmojiManager>,
     pub settings_manager: Arc<SettingsManager>,
+    pub window_state_manager: Arc<WindowStateManager>,
     pub opening_settings: Arc<AtomicBool>,
     pub opening_help: Arc<AtomicBool>,
-    pub current_shortcut: Arc<Mutex<Shortcut>>,
-    pub shortcut_pressed: Arc<AtomicBool>,
+    /// Reverse lookup from the `Shortcut` the OS hands back to the global
+    /// shortcut handler to the logical action it was registered for.
+    pub registered_hotkeys: Arc<Mutex<HashMap<Shortcut, HotkeyAction>>>,
+    /// Shortcuts currently held down, so concurrently-configured hotkeys
+    /// don't clobber each other's press/release duplicate-event guard.
+    pub pressed_shortcuts: Arc<Mutex<HashSet<Shortcut>>>,
+    /// Bumped every time the idle auto-hide timer is (re)armed or cancelled;
+    /// a pending timer task no-ops if its generation is stale when it wakes.
+    pub idle_timer_generation: Arc<AtomicU64>,
+    /// Set when an emoji is copied in `CopyOnly` mode, cleared by
+    /// `restore_previous_app` once it's consumed (or by `store_previous_app`
+    /// when the panel is shown again). Gates `Settings::auto_paste` so
+    /// dismissing the picker without picking anything never pastes whatever
+    /// was already on the clipboard.
+    pub pending_auto_paste: Arc<AtomicBool>,
+}
+
+/// Route a fired hotkey action to its handler. `ShowPanel` toggles the
+/// picker directly; the paste actions run async since they may type into
+/// the previously focused app.
+fn dispatch_hotkey_action(app: &tauri::AppHandle, action: HotkeyAction) {
+    match action {
+        HotkeyAction::ShowPanel => {
+            let _ = panel::toggle_panel(app.clone());
+        }
+        HotkeyAction::PasteRecent => {
+            let Some(state) = app.try_state::<AppState>() else {
+                return;
+            };
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = command::paste_recent_emoji(handle, state).await {
+                    log::error!("Hotkey 'paste recent' failed: {}", e);
+                }
+            });
+        }
+        HotkeyAction::PasteTopRanked => {
+            let Some(state) = app.try_state::<AppState>() else {
+                return;
+            };
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = command::paste_top_ranked_emoji(handle, state).await {
+                    log::error!("Hotkey 'paste top ranked' failed: {}", e);
+                }
+            });
+        }
+    }
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -60,31 +113,55 @@ pub fn run() {
     );
 
     tauri::Builder::default()
+        // Forward CLI subcommands from a second launch to this (already running) instance
+        // instead of spawning a second app window.
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            cli::dispatch_args(app, &argv);
+        }))
+        .plugin(tauri_plugin_autostart::init(
+            tauri_plugin_autostart::MacosLauncher::LaunchAgent,
+            None,
+        ))
         .plugin(tauri_plugin_macos_permissions::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_nspanel::init())
+        // Custom chromeless titlebar for the Settings/Help windows (see `tray.rs`)
+        .plugin(tauri_plugin_decorum::init())
         // Initialize global shortcut plugin FIRST with a single global handler
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
-                .with_handler(|app, _shortcut, event| {
-                    if let Some(state) = app.try_state::<crate::AppState>() {
-                        match event.state {
-                            ShortcutState::Pressed => {
-                                state.shortcut_pressed.store(true, Ordering::Relaxed);
+                .with_handler(|app, shortcut, event| {
+                    let Some(state) = app.try_state::<crate::AppState>() else {
+                        return;
+                    };
+                    let action = {
+                        let Ok(registered) = state.registered_hotkeys.lock() else {
+                            return;
+                        };
+                        let Some(action) = registered.get(shortcut) else {
+                            return;
+                        };
+                        *action
+                    };
+                    match event.state {
+                        ShortcutState::Pressed => {
+                            if let Ok(mut pressed) = state.pressed_shortcuts.lock() {
+                                pressed.insert(*shortcut);
                             }
-                            ShortcutState::Released => {
-                                let was_pressed = state
-                                    .shortcut_pressed
-                                    .swap(false, Ordering::Relaxed);
-                                if !was_pressed {
-                                    log::warn!("Global handler: Ignoring duplicate release");
-                                    return;
-                                }
-                                let handle = app.app_handle();
-                                let _ = panel::toggle_panel(handle.clone());
+                        }
+                        ShortcutState::Released => {
+                            let was_pressed = state
+                                .pressed_shortcuts
+                                .lock()
+                                .map(|mut pressed| pressed.remove(shortcut))
+                                .unwrap_or(false);
+                            if !was_pressed {
+                                log::warn!("Global handler: Ignoring duplicate release");
+                                return;
                             }
+                            dispatch_hotkey_action(&app.app_handle(), action);
                         }
                     }
                 })
@@ -97,6 +174,11 @@ pub fn run() {
             command::reset_accessibility_cache,
             command::get_emojis,
             command::get_keywords,
+            command::get_variants,
+            command::resolve_shortcode,
+            command::get_categories,
+            command::get_emojis_by_category,
+            command::reload_emoji_packs,
             command::increment_usage,
             command::remove_emoji_rank,
             command::reset_emoji_ranks,
@@ -104,9 +186,13 @@ pub fn run() {
             command::update_settings,
             command::open_settings,
             command::save_window_size,
+            command::save_window_position,
+            command::normalize_hotkey,
             command::reregister_hotkey,
             command::open_help,
             command::close_help,
+            command::paste_recent_emoji,
+            command::paste_top_ranked_emoji,
         ])
         .setup(move |app| {
             // Set activation policy to Accessory to prevent the app icon from showing on the dock
@@ -122,10 +208,21 @@ pub fn run() {
                 dir
             };
 
-            let emoji_manager = Arc::new(EmojiManager::new(
-                PathBuf::from(constants::DEFAULT_EMOJI_FILE),
-                ranks_file_path,
-            ));
+            let custom_overlay_path: PathBuf = {
+                let mut dir = app.path().app_data_dir()?;
+                dir.push(constants::DEFAULT_CUSTOM_EMOJI_FILE);
+                dir
+            };
+            let packs_dir_path: PathBuf = {
+                let mut dir = app.path().app_data_dir()?;
+                dir.push(constants::DEFAULT_EMOJI_PACKS_DIR);
+                dir
+            };
+
+            let emoji_manager = Arc::new(
+                EmojiManager::new(PathBuf::from(constants::DEFAULT_EMOJI_FILE), ranks_file_path)
+                    .with_custom_sources(Some(custom_overlay_path), Some(packs_dir_path)),
+            );
             if let Err(e) = emoji_manager.initialize() {
                 log::warn!("Failed to initialize emoji manager: {}", e);
             }
@@ -145,6 +242,34 @@ pub fn run() {
                 log::warn!("Failed to initialize settings manager: {}", e);
             }
 
+            // Apply the saved search language, if it's not the English default
+            if let Ok(settings) = settings_manager.get() {
+                if settings.search_language != settings::SearchLanguage::default() {
+                    if let Err(e) = emoji_manager.set_search_language(settings.search_language) {
+                        log::warn!("Failed to apply saved search language: {}", e);
+                    }
+                }
+                if settings.default_skin_tone != settings::SkinTone::default() {
+                    if let Err(e) = emoji_manager.set_default_skin_tone(settings.default_skin_tone) {
+                        log::warn!("Failed to apply saved default skin tone: {}", e);
+                    }
+                }
+            }
+
+            // Initialize window state manager (position/size) next to settings.json
+            let window_state_file_path: PathBuf = {
+                let mut dir = app.path().app_data_dir()?;
+                dir.push(constants::DEFAULT_WINDOW_STATE_FILE);
+                dir
+            };
+            let window_state_manager = Arc::new(window_state::WindowStateManager::new(
+                window_state_file_path,
+                window_state::StateFlags::default(),
+            ));
+            if let Err(e) = window_state_manager.initialize() {
+                log::warn!("Failed to initialize window state manager: {}", e);
+            }
+
             // Check accessibility permissions at startup only if needed for the current mode
             let settings_manager_clone = settings_manager.clone();
             tauri::async_runtime::spawn(async move {
@@ -163,13 +288,19 @@ pub fn run() {
                 }
             });
 
+            let mut initial_hotkeys = HashMap::new();
+            initial_hotkeys.insert(shortcut.clone(), HotkeyAction::ShowPanel);
+
             let app_state = AppState {
                 emoji_manager,
                 settings_manager,
+                window_state_manager,
                 opening_settings: Arc::new(AtomicBool::new(false)),
                 opening_help: Arc::new(AtomicBool::new(false)),
-                current_shortcut: Arc::new(Mutex::new(shortcut.clone())),
-                shortcut_pressed: Arc::new(AtomicBool::new(false)),
+                registered_hotkeys: Arc::new(Mutex::new(initial_hotkeys)),
+                pressed_shortcuts: Arc::new(Mutex::new(HashSet::new())),
+                idle_timer_generation: Arc::new(AtomicU64::new(0)),
+                pending_auto_paste: Arc::new(AtomicBool::new(false)),
             };
             app.manage(app_state);
 
@@ -178,11 +309,29 @@ pub fn run() {
             panel::init(&app_handle)?;
             tray::init(&app_handle)?;
 
+            // Live-reload settings.json on hand-edits (or sync from another machine)
+            if let Some(state) = app_handle.try_state::<crate::AppState>() {
+                state
+                    .settings_manager
+                    .start_watching(app_handle.clone());
+            }
+
+            // Reconcile the OS launch agent with the persisted start_on_login setting
+            if let Some(state) = app_handle.try_state::<crate::AppState>() {
+                if let Ok(settings) = state.settings_manager.get() {
+                    autostart::reconcile(&app_handle, settings.start_on_login);
+                }
+            }
+
             // Register initial global shortcut (single central handler already set by plugin)
             if let Err(e) = app_handle.global_shortcut().register(shortcut.clone()) {
                 log::error!("Failed to register initial hotkey: {}", e);
             }
 
+            // Dispatch our own CLI args, in case this is the primary launch
+            // (single-instance only forwards args from a *second* launch).
+            cli::dispatch_args(&app_handle, &std::env::args().collect::<Vec<_>>());
+
             // Show Help window on first launch
             if first_launch {
                 if let Err(e) = tray::open_help_window(&app_handle) {
@@ -190,37 +339,22 @@ pub fn run() {
                 }
             }
 
-            // After settings manager has loaded, re-register to saved hotkey if different
+            // After settings manager has loaded, re-register the full hotkey set if it
+            // differs from the default we registered above.
             {
                 let handle_clone = app_handle.clone();
                 tauri::async_runtime::spawn(async move {
-                    // Read desired hotkey from settings
-                    if let Some(state) = handle_clone.try_state::<crate::AppState>() {
-                        if let Ok(settings) = state.settings_manager.get() {
-                            if settings.global_hotkey != constants::DEFAULT_GLOBAL_HOTKEY {
-                                // Parse new shortcut
-                                if let Ok(new_shortcut) = crate::hotkey::parse_hotkey(&settings.global_hotkey) {
-                                    // Unregister all, wait, register new
-                                    if let Err(e) = handle_clone.global_shortcut().unregister_all() {
-                                        log::error!("Failed to unregister shortcuts: {}", e);
-                                        return;
-                                    }
-                                    let delay = std::time::Duration::from_millis(
-                                        crate::constants::HOTKEY_UNREGISTER_WAIT_MS,
-                                    );
-                                    let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
-                                    if let Err(e) = handle_clone.global_shortcut().register(new_shortcut.clone()) {
-                                        log::error!("Failed to register saved hotkey: {}", e);
-                                        return;
-                                    }
-                                    if let Ok(mut guard) = state.current_shortcut.lock() {
-                                        *guard = new_shortcut;
-                                    }
-                                    log::info!(
-                                        "Hotkey re-registered to saved setting: {}",
-                                        settings.global_hotkey
-                                    );
-                                }
+                    let Some(state) = handle_clone.try_state::<crate::AppState>() else {
+                        return;
+                    };
+                    if let Ok(settings) = state.settings_manager.get() {
+                        if settings.hotkeys != settings::Settings::default().hotkeys {
+                            if let Err(e) =
+                                command::reregister_hotkey(handle_clone.clone(), state).await
+                            {
+                                log::error!("Failed to re-register saved hotkeys: {}", e);
+                            } else {
+                                log::info!("Hotkeys re-registered to saved settings");
                             }
                         }
                     }
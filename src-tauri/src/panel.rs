@@ -5,7 +5,11 @@
 
 use crate::constants::*;
 use crate::errors::EmojiError;
-use crate::positioning::{position_window_at_cursor, restore_previous_app, store_previous_app};
+use crate::positioning::{
+    clamp_position_to_visible_screen, position_window_at_cursor, restore_previous_app,
+    store_previous_app,
+};
+use crate::window_state::StateFlags;
 use tauri::{AppHandle, Manager, WebviewWindow};
 use tauri_nspanel::{tauri_panel, CollectionBehavior, ManagerExt, StyleMask, WebviewWindowExt};
 
@@ -50,6 +54,43 @@ pub fn init(app_handle: &AppHandle) -> tauri::Result<()> {
                 height: settings.window_height,
             }));
         }
+
+        // Restore the panel's last screen position, unless it's always repositioned
+        // under the mouse cursor on show (in which case a saved position is moot).
+        let place_under_mouse = state
+            .settings_manager
+            .get_place_under_mouse()
+            .unwrap_or(true);
+        if !place_under_mouse && state.window_state_manager.restores(StateFlags::POSITION) {
+            if let Ok(saved) = state.window_state_manager.get() {
+                if let (Some(x), Some(y)) = (saved.x, saved.y) {
+                    let size = window.outer_size().unwrap_or(tauri::PhysicalSize::new(
+                        DEFAULT_WINDOW_WIDTH as u32,
+                        DEFAULT_WINDOW_HEIGHT as u32,
+                    ));
+
+                    #[cfg(target_os = "macos")]
+                    let (clamped_x, clamped_y) = clamp_position_to_visible_screen(
+                        x,
+                        y,
+                        size.width as f64,
+                        size.height as f64,
+                    );
+                    #[cfg(not(target_os = "macos"))]
+                    let (clamped_x, clamped_y) = clamp_position_to_visible_screen(
+                        &window,
+                        x,
+                        y,
+                        size.width as f64,
+                        size.height as f64,
+                    );
+
+                    let _ = window.set_position(tauri::Position::Logical(
+                        tauri::LogicalPosition::new(clamped_x, clamped_y),
+                    ));
+                }
+            }
+        }
     }
 
     let panel = window.to_panel::<EmojiqPanel>().map_err(|e| {
@@ -63,14 +104,6 @@ pub fn init(app_handle: &AppHandle) -> tauri::Result<()> {
     // Prevent panel from activating the app (required for fullscreen display)
     panel.set_style_mask(StyleMask::empty().nonactivating_panel().into());
 
-    // Allow panel to display over fullscreen windows and join all spaces
-    panel.set_collection_behavior(
-        CollectionBehavior::new()
-            .full_screen_auxiliary()
-            .can_join_all_spaces()
-            .into(),
-    );
-
     // without it - panel edges are not rounded, only window edges are rounded
     panel.set_corner_radius(PANEL_CORNER_RADIUS);
 
@@ -106,16 +139,108 @@ pub fn init(app_handle: &AppHandle) -> tauri::Result<()> {
 
         // Try to focus settings window if it's open, otherwise restore previous app
         if !try_focus_settings(&handle_for_handler) {
-            restore_previous_app();
+            restore_previous_app(&handle_for_handler);
         }
     });
 
     panel.set_event_handler(Some(handler.as_ref()));
 
+    // Allow panel to display over fullscreen windows and join all spaces, if enabled
+    let visible_on_all_workspaces = app_handle
+        .try_state::<crate::AppState>()
+        .and_then(|state| state.settings_manager.get().ok())
+        .map(|settings| settings.visible_on_all_workspaces)
+        .unwrap_or(true);
+    set_visible_on_all_workspaces(app_handle, visible_on_all_workspaces)?;
+
     Ok(())
 }
 
+/// Apply (or clear) the NSPanel collection behavior that lets the panel join
+/// every macOS Space and float over fullscreen apps.
+pub fn set_visible_on_all_workspaces(
+    handle: &AppHandle,
+    visible_on_all_workspaces: bool,
+) -> Result<(), EmojiError> {
+    let panel = handle
+        .get_webview_panel("main")
+        .map_err(|e| EmojiError::Panel(format!("Failed to get main panel: {:?}", e)))?;
+
+    let behavior = if visible_on_all_workspaces {
+        CollectionBehavior::new()
+            .full_screen_auxiliary()
+            .can_join_all_spaces()
+    } else {
+        CollectionBehavior::new()
+    };
+    panel.set_collection_behavior(behavior.into());
+
+    Ok(())
+}
+
+/// Bump the idle-timer generation so any pending auto-hide task becomes
+/// stale and no-ops when it wakes, without needing a real cancel handle.
+fn cancel_idle_timer(handle: &AppHandle) {
+    if let Some(state) = handle.try_state::<crate::AppState>() {
+        state
+            .idle_timer_generation
+            .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+}
+
+/// (Re)start the idle auto-hide timer per the configured `idle_timeout`
+/// (0 disables it). Call whenever the panel is shown or sees activity, so a
+/// forgotten picker doesn't sit open and later steal focus restoration.
+pub fn reset_idle_timer(handle: &AppHandle) {
+    let Some(state) = handle.try_state::<crate::AppState>() else {
+        return;
+    };
+    let Ok(settings) = state.settings_manager.get() else {
+        return;
+    };
+    if settings.idle_timeout == 0 {
+        cancel_idle_timer(handle);
+        return;
+    }
+
+    let generation = state
+        .idle_timer_generation
+        .fetch_add(1, std::sync::atomic::Ordering::SeqCst)
+        + 1;
+
+    let handle = handle.clone();
+    tauri::async_runtime::spawn(async move {
+        let delay = std::time::Duration::from_secs(settings.idle_timeout);
+        let _ = tauri::async_runtime::spawn_blocking(move || std::thread::sleep(delay)).await;
+
+        let Some(state) = handle.try_state::<crate::AppState>() else {
+            return;
+        };
+        let still_current = state
+            .idle_timer_generation
+            .load(std::sync::atomic::Ordering::SeqCst)
+            == generation;
+        if !still_current {
+            return;
+        }
+
+        // Don't yank the panel away while the user is navigating to Settings/Help.
+        let navigating_away = state
+            .opening_settings
+            .load(std::sync::atomic::Ordering::Acquire)
+            || state.opening_help.load(std::sync::atomic::Ordering::Acquire);
+        if navigating_away {
+            return;
+        }
+
+        log::debug!("Idle timeout reached, auto-hiding panel");
+        let _ = hide_panel(handle);
+    });
+}
+
 pub fn hide_panel(handle: AppHandle) -> Result<(), EmojiError> {
+    cancel_idle_timer(&handle);
+
     let panel = handle
         .get_webview_panel("main")
         .map_err(|e| EmojiError::Panel(format!("Failed to get main panel: {:?}", e)))?;
@@ -139,7 +264,7 @@ pub fn show_panel(handle: AppHandle) -> Result<(), EmojiError> {
 
     // Only store the previous app if settings window is not currently focused
     if !settings_is_open {
-        store_previous_app();
+        store_previous_app(&handle);
     }
 
     // Get the window first, then convert to panel (more reliable)
@@ -174,6 +299,8 @@ pub fn show_panel(handle: AppHandle) -> Result<(), EmojiError> {
             .map_err(|e| EmojiError::Panel(format!("Failed to get main panel: {:?}", e)))?;
         panel.show_and_make_key();
 
+        reset_idle_timer(&handle);
+
         Ok(())
     } else {
         Err(EmojiError::Panel("Failed to get main window".to_string()))
@@ -1,6 +1,7 @@
 #![cfg_attr(target_os = "macos", allow(unexpected_cfgs))]
 use crate::errors::EmojiError;
 use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager};
 #[cfg(not(target_os = "macos"))]
 use tauri::{PhysicalPosition, Position};
 
@@ -24,6 +25,56 @@ lazy_static::lazy_static! {
     static ref PREVIOUS_APP: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
 }
 
+/// Find the `visibleFrame` of the screen containing `point` (bottom-left
+/// origin, global coordinates, matching `NSEvent.mouseLocation`). Falls back
+/// to the screen whose frame center is nearest `point` (the cursor can land
+/// between displays during a hot-unplug), and only then to `mainScreen`.
+#[cfg(target_os = "macos")]
+unsafe fn visible_frame_containing(
+    point: cocoa::foundation::NSPoint,
+) -> cocoa::foundation::NSRect {
+    use cocoa::appkit::NSScreen;
+    use cocoa::foundation::{NSArray, NSRect};
+
+    let screens = NSScreen::screens(nil);
+    let count = NSArray::count(screens);
+
+    let mut nearest: Option<(f64, NSRect)> = None;
+
+    for i in 0..count {
+        let screen = NSArray::objectAtIndex(screens, i);
+        let frame: NSRect = NSScreen::frame(screen);
+
+        let contains_point = point.x >= frame.origin.x
+            && point.x <= frame.origin.x + frame.size.width
+            && point.y >= frame.origin.y
+            && point.y <= frame.origin.y + frame.size.height;
+
+        if contains_point {
+            return NSScreen::visibleFrame(screen);
+        }
+
+        let center_x = frame.origin.x + frame.size.width / 2.0;
+        let center_y = frame.origin.y + frame.size.height / 2.0;
+        let distance = ((center_x - point.x).powi(2) + (center_y - point.y).powi(2)).sqrt();
+
+        let is_closer = match &nearest {
+            Some((best_distance, _)) => distance < *best_distance,
+            None => true,
+        };
+        if is_closer {
+            nearest = Some((distance, NSScreen::visibleFrame(screen)));
+        }
+    }
+
+    if let Some((_, visible_frame)) = nearest {
+        return visible_frame;
+    }
+
+    let main_screen = NSScreen::mainScreen(std::ptr::null_mut());
+    NSScreen::visibleFrame(main_screen)
+}
+
 /// Position panel at cursor using the window directly
 /// This function works by taking a Tauri WebviewWindow and positioning it smartly
 /// It avoids the dock and menu bar by using the visible screen area
@@ -46,8 +97,9 @@ pub fn position_window_at_cursor(window: &tauri::WebviewWindow) -> Result<(), Em
             let window_width = window_frame.size.width;
             let window_height = window_frame.size.height;
 
-            let main_screen = cocoa::appkit::NSScreen::mainScreen(std::ptr::null_mut());
-            let screen_visible_frame = cocoa::appkit::NSScreen::visibleFrame(main_screen);
+            // Use the screen under the cursor, not always the main screen, so the
+            // panel lands on (and is clamped to) whichever monitor the user is on.
+            let screen_visible_frame = visible_frame_containing(raw_mouse_location);
 
             (
                 raw_mouse_location,
@@ -87,11 +139,38 @@ pub fn position_window_at_cursor(window: &tauri::WebviewWindow) -> Result<(), Em
     }
 }
 
+/// Clamp a restored window position onto the visible frame of the screen it
+/// was saved on, so a position saved on a now-disconnected (or resized)
+/// display doesn't leave the panel off-screen.
+#[cfg(target_os = "macos")]
+pub fn clamp_position_to_visible_screen(x: f64, y: f64, width: f64, height: f64) -> (f64, f64) {
+    unsafe {
+        let visible_frame = visible_frame_containing(cocoa::foundation::NSPoint { x, y });
+
+        let final_x = x
+            .max(visible_frame.origin.x)
+            .min(visible_frame.origin.x + visible_frame.size.width - width);
+        let final_y = y
+            .max(visible_frame.origin.y)
+            .min(visible_frame.origin.y + visible_frame.size.height - height);
+
+        (final_x, final_y)
+    }
+}
+
 // Function to store the currently active application
 #[cfg(target_os = "macos")]
-pub fn store_previous_app() {
+pub fn store_previous_app(handle: &AppHandle) {
     log::debug!("Storing previous app...");
 
+    // Starting a fresh picker session: any emoji copied during a previous
+    // session is stale, so don't let it auto-paste on this one's dismissal.
+    if let Some(state) = handle.try_state::<crate::AppState>() {
+        state
+            .pending_auto_paste
+            .store(false, std::sync::atomic::Ordering::SeqCst);
+    }
+
     #[cfg(target_os = "macos")]
     unsafe {
         let is_main = pthread_main_np() != 0;
@@ -125,14 +204,63 @@ pub fn store_previous_app() {
     }
 }
 
+/// Synthesize a Cmd+V keystroke via CoreGraphics HID event injection, for
+/// `Settings::auto_paste`. Posted straight to the HID event tap (rather than
+/// Enigo's cross-platform path) so it can run right after `activateWithOptions`
+/// on the same dispatch, with no extra round trip into the app's own runtime.
+#[cfg(target_os = "macos")]
+fn synthesize_paste_keystroke() -> Result<(), EmojiError> {
+    use core_graphics::event::{CGEvent, CGEventFlags, CGEventTapLocation};
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    const KEY_CODE_V: core_graphics::event::CGKeyCode = 9;
+
+    let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState)
+        .map_err(|_| EmojiError::Positioning("Failed to create CGEventSource".to_string()))?;
+
+    let key_down = CGEvent::new_keyboard_event(source.clone(), KEY_CODE_V, true)
+        .map_err(|_| EmojiError::Positioning("Failed to create key-down CGEvent".to_string()))?;
+    key_down.set_flags(CGEventFlags::CGEventFlagCommand);
+    key_down.post(CGEventTapLocation::HID);
+
+    let key_up = CGEvent::new_keyboard_event(source, KEY_CODE_V, false)
+        .map_err(|_| EmojiError::Positioning("Failed to create key-up CGEvent".to_string()))?;
+    key_up.set_flags(CGEventFlags::CGEventFlagCommand);
+    key_up.post(CGEventTapLocation::HID);
+
+    Ok(())
+}
+
 // Function to restore focus to the previously active application
 #[cfg(target_os = "macos")]
-pub fn restore_previous_app() {
+pub fn restore_previous_app(handle: &AppHandle) {
     log::debug!("Restoring previous app...");
 
     if let Ok(previous_app) = PREVIOUS_APP.lock() {
         if let Some(bundle_id) = previous_app.as_ref() {
             log::debug!("Restoring focus to: {}", bundle_id);
+            // Consume (and reset) the "an emoji was copied this session" flag
+            // so auto-paste only fires once, and only when the picker was
+            // actually used to copy something - not on every dismissal
+            // (Escape, click-away, ...) while the setting happens to be on.
+            let emoji_was_copied = handle
+                .try_state::<crate::AppState>()
+                .map(|state| {
+                    state
+                        .pending_auto_paste
+                        .swap(false, std::sync::atomic::Ordering::SeqCst)
+                })
+                .unwrap_or(false);
+            let auto_paste_enabled = handle
+                .try_state::<crate::AppState>()
+                .and_then(|state| state.settings_manager.get().ok())
+                .map(|settings| {
+                    settings.auto_paste
+                        && settings.emoji_mode == crate::settings::EmojiMode::CopyOnly
+                })
+                .unwrap_or(false);
+            let auto_paste = emoji_was_copied && auto_paste_enabled;
+
             // Use native Cocoa APIs to activate the app on the main thread
             #[cfg(target_os = "macos")]
             {
@@ -152,6 +280,12 @@ pub fn restore_previous_app() {
                                 let app = cocoa::foundation::NSArray::objectAtIndex(apps, 0);
                                 // NSApplicationActivateIgnoringOtherApps = 1
                                 let _: bool = msg_send![app, activateWithOptions: 1u64];
+
+                                if auto_paste {
+                                    if let Err(e) = synthesize_paste_keystroke() {
+                                        log::warn!("Auto-paste after focus restore failed: {}", e);
+                                    }
+                                }
                             } else {
                                 log::warn!("No running app found with bundle id: {}", bundle_id_owned);
                             }
@@ -167,135 +301,179 @@ pub fn restore_previous_app() {
     }
 }
 
-// Non-macOS positioning
-// Not tested, partially implemented, so commented for the great future
-
-// // Simple rect structure for non-macOS platforms
-// #[cfg(not(target_os = "macos"))]
-// #[derive(Debug, Clone, Copy)]
-// struct SimpleRect {
-//     x: f64,
-//     y: f64,
-//     width: f64,
-//     height: f64,
-// }
-
-// // Trait to unify rect access across platforms
-// #[cfg(not(target_os = "macos"))]
-// impl RectAccess for SimpleRect {
-//     fn left(&self) -> f64 {
-//         self.x
-//     }
-//     fn top(&self) -> f64 {
-//         self.y
-//     }
-//     fn width(&self) -> f64 {
-//         self.width
-//     }
-//     fn height(&self) -> f64 {
-//         self.height
-//     }
-// }
-
-// #[cfg(not(target_os = "macos"))]
-// fn get_visible_screen_area() -> Result<(SimpleRect, f64), PositioningError> {
-//     // Fallback to monitor API for non-macOS
-//     let monitor = get_monitor_with_cursor().ok_or(PositioningError::MonitorNotFound)?;
-//     let monitor_scale_factor = monitor.scale_factor();
-//     let monitor_size = monitor.size().to_logical::<f64>(monitor_scale_factor);
-//     let monitor_position = monitor.position().to_logical::<f64>(monitor_scale_factor);
-
-//     let rect = SimpleRect {
-//         x: monitor_position.x,
-//         y: monitor_position.y,
-//         width: monitor_size.width,
-//         height: monitor_size.height,
-//     };
-//     Ok((rect, monitor_scale_factor))
-// }
-
-// #[cfg(not(target_os = "macos"))]
-// pub fn position_window_at_cursor(window: &tauri::WebviewWindow) -> Result<(), PositioningError> {
-//     {
-//         // Fallback to Tauri positioning (for non-macOS)
-//         // Get cursor position in screen coordinates
-
-//         // TODO: Implement this
-//         Err(PositioningError::MonitorNotFound);
-//         // let cursor_pos = get_cursor_position()?;
-
-//         // Get panel size using Tauri API
-//         let panel_size = window
-//             .outer_size()
-//             .map_err(|_| PositioningError::WindowHandleError)?;
-
-//         // Get visible screen area (excluding dock and menu bar) for the screen with cursor
-//         let (visible_area, scale_factor) = get_visible_screen_area()?;
-
-//         // Convert panel size to logical pixels to match cursor coordinates
-//         let panel_logical_size = PhysicalPosition {
-//             x: panel_size.width as f64 / scale_factor,
-//             y: panel_size.height as f64 / scale_factor,
-//         };
-
-//         // Calculate visible area bounds in logical coordinates (to match cursor)
-//         let visible_left = visible_area.left();
-//         let visible_top = visible_area.top();
-//         let visible_right = visible_left + visible_area.width();
-//         let visible_bottom = visible_top + visible_area.height();
-
-//         // Calculate if panel fits in each direction from cursor (using logical coordinates)
-//         let fits_right = cursor_pos.x + panel_logical_size.x <= visible_right;
-//         let fits_below = cursor_pos.y + panel_logical_size.y <= visible_bottom;
-
-//         // Determine panel position based on available space (using logical coordinates)
-//         let panel_x = if fits_right {
-//             cursor_pos.x // Top-left or bottom-left at cursor
-//         } else {
-//             cursor_pos.x - panel_logical_size.x // Top-right or bottom-right at cursor
-//         };
-
-//         let panel_y = if fits_below {
-//             cursor_pos.y // Top-left or top-right at cursor
-//         } else {
-//             cursor_pos.y - panel_logical_size.y // Bottom-left or bottom-right at cursor
-//         };
-
-//         // Ensure the panel stays within visible area bounds (safety clamp, using logical coordinates)
-//         let final_x = panel_x
-//             .max(visible_left)
-//             .min(visible_right - panel_logical_size.x);
-//         let final_y = panel_y
-//             .max(visible_top)
-//             .min(visible_bottom - panel_logical_size.y);
-
-//         // Convert back to physical coordinates for Tauri API
-//         let physical_x = (final_x * scale_factor) as i32;
-//         let physical_y = (final_y * scale_factor) as i32;
-
-//         // Set panel position using Tauri API
-//         let position = Position::Physical(PhysicalPosition {
-//             x: physical_x,
-//             y: physical_y,
-//         });
-
-//         window
-//             .set_position(position)
-//             .map_err(|_| PositioningError::WindowHandleError)?;
-
-//         Ok(())
-//     }
-// }
-
-// #[cfg(not(target_os = "macos"))]
-// pub fn store_previous_app() {
-//     // No-op for non-macOS platforms
-// }
-
-// #[cfg(not(target_os = "macos"))]
-// pub fn restore_previous_app() {
-//     // No-op for non-macOS platforms
-// }
-
-// #[cfg(not(target_os = "macos"))]
-// use monitor::get_monitor_with_cursor;
+// Non-macOS positioning (Windows/Linux), via Tauri's own monitor APIs rather
+// than raw platform calls.
+
+/// Find the monitor whose logical rect contains `point`, falling back to the
+/// window's current monitor, and then its primary monitor, so a point just
+/// outside every monitor's rect (multi-monitor corner rounding, or a display
+/// that's since been unplugged) still resolves to somewhere sane.
+#[cfg(not(target_os = "macos"))]
+fn monitor_containing_logical_point(
+    window: &tauri::WebviewWindow,
+    point: tauri::LogicalPosition<f64>,
+) -> Result<tauri::Monitor, EmojiError> {
+    let monitors = window
+        .available_monitors()
+        .map_err(|e| EmojiError::Positioning(format!("Failed to enumerate monitors: {}", e)))?;
+
+    let containing = monitors.into_iter().find(|monitor| {
+        let scale_factor = monitor.scale_factor();
+        let position = monitor.position().to_logical::<f64>(scale_factor);
+        let size = monitor.size().to_logical::<f64>(scale_factor);
+        point.x >= position.x
+            && point.x <= position.x + size.width
+            && point.y >= position.y
+            && point.y <= position.y + size.height
+    });
+
+    if let Some(monitor) = containing {
+        return Ok(monitor);
+    }
+
+    window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .or(window.primary_monitor().ok().flatten())
+        .ok_or(EmojiError::MonitorNotFound)
+}
+
+/// Clamp a restored window position onto whichever monitor it falls on (or
+/// the primary monitor if that display has since been unplugged), so a
+/// position saved on a now-disconnected or resized display doesn't leave the
+/// panel off-screen. Mirrors the macOS `clamp_position_to_visible_screen`.
+#[cfg(not(target_os = "macos"))]
+pub fn clamp_position_to_visible_screen(
+    window: &tauri::WebviewWindow,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+) -> (f64, f64) {
+    let Ok(monitor) =
+        monitor_containing_logical_point(window, tauri::LogicalPosition::new(x, y))
+    else {
+        return (x, y);
+    };
+
+    let scale_factor = monitor.scale_factor();
+    let monitor_position = monitor.position().to_logical::<f64>(scale_factor);
+    let monitor_size = monitor.size().to_logical::<f64>(scale_factor);
+
+    let final_x = x
+        .max(monitor_position.x)
+        .min(monitor_position.x + monitor_size.width - width);
+    let final_y = y
+        .max(monitor_position.y)
+        .min(monitor_position.y + monitor_size.height - height);
+
+    (final_x, final_y)
+}
+
+/// Find the monitor under `cursor_pos` (physical coordinates), falling back
+/// to the window's current monitor, and then its primary monitor, so a
+/// cursor reported just outside every monitor's rect (multi-monitor corner
+/// rounding) still resolves to somewhere sane.
+#[cfg(not(target_os = "macos"))]
+fn monitor_under_cursor(
+    window: &tauri::WebviewWindow,
+    cursor_pos: PhysicalPosition<f64>,
+) -> Result<tauri::Monitor, EmojiError> {
+    let monitors = window
+        .available_monitors()
+        .map_err(|e| EmojiError::Positioning(format!("Failed to enumerate monitors: {}", e)))?;
+
+    let containing = monitors.into_iter().find(|monitor| {
+        let position = monitor.position();
+        let size = monitor.size();
+        cursor_pos.x >= position.x as f64
+            && cursor_pos.x <= (position.x + size.width as i32) as f64
+            && cursor_pos.y >= position.y as f64
+            && cursor_pos.y <= (position.y + size.height as i32) as f64
+    });
+
+    if let Some(monitor) = containing {
+        return Ok(monitor);
+    }
+
+    window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .or(window.primary_monitor().ok().flatten())
+        .ok_or(EmojiError::MonitorNotFound)
+}
+
+/// Position the panel at the cursor on Windows/Linux using Tauri's monitor
+/// APIs: anchored to the cursor, flipped left/above when it would overflow
+/// the monitor's bounds, and clamped to stay fully on-screen, accounting for
+/// that monitor's own `scale_factor` (mixed-DPI multi-monitor setups don't
+/// share a single scale). Unlike the macOS path, which only clamps, this one
+/// flips. The bounds are the monitor's full rect; space taken by a taskbar
+/// or panel is not excluded.
+#[cfg(not(target_os = "macos"))]
+pub fn position_window_at_cursor(window: &tauri::WebviewWindow) -> Result<(), EmojiError> {
+    let cursor_pos = window
+        .cursor_position()
+        .map_err(|e| EmojiError::Positioning(format!("Failed to get cursor position: {}", e)))?;
+
+    let monitor = monitor_under_cursor(window, cursor_pos)?;
+    let scale_factor = monitor.scale_factor();
+
+    let monitor_position = monitor.position().to_logical::<f64>(scale_factor);
+    let monitor_size = monitor.size().to_logical::<f64>(scale_factor);
+    let visible_left = monitor_position.x;
+    let visible_top = monitor_position.y;
+    let visible_right = visible_left + monitor_size.width;
+    let visible_bottom = visible_top + monitor_size.height;
+
+    let window_size = window
+        .outer_size()
+        .map_err(|_| EmojiError::WindowHandle)?
+        .to_logical::<f64>(scale_factor);
+
+    let cursor_logical = cursor_pos.to_logical::<f64>(scale_factor);
+
+    // Flip left/above when the panel would overflow the monitor's bounds.
+    let fits_right = cursor_logical.x + window_size.width <= visible_right;
+    let fits_below = cursor_logical.y + window_size.height <= visible_bottom;
+
+    let desired_x = if fits_right {
+        cursor_logical.x
+    } else {
+        cursor_logical.x - window_size.width
+    };
+    let desired_y = if fits_below {
+        cursor_logical.y
+    } else {
+        cursor_logical.y - window_size.height
+    };
+
+    let final_x = desired_x
+        .max(visible_left)
+        .min(visible_right - window_size.width);
+    let final_y = desired_y
+        .max(visible_top)
+        .min(visible_bottom - window_size.height);
+
+    let physical_position =
+        tauri::LogicalPosition::new(final_x, final_y).to_physical::<i32>(scale_factor);
+
+    window
+        .set_position(Position::Physical(physical_position))
+        .map_err(|e| EmojiError::Positioning(format!("Failed to set window position: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn store_previous_app(_handle: &AppHandle) {
+    // No-op on Windows/Linux: focus restoration there is handled by the OS
+    // returning focus to the previously-focused window when the panel hides.
+}
+
+#[cfg(not(target_os = "macos"))]
+pub fn restore_previous_app(_handle: &AppHandle) {
+    // No-op on Windows/Linux: see `store_previous_app`.
+}
@@ -1,12 +1,18 @@
 use crate::constants::{
-    DEFAULT_GLOBAL_HOTKEY, DEFAULT_MAX_TOP_EMOJIS, DEFAULT_PLACE_UNDER_MOUSE, DEFAULT_SCALE_FACTOR,
-    DEFAULT_WINDOW_HEIGHT, DEFAULT_WINDOW_WIDTH,
+    DEFAULT_AUTO_PASTE, DEFAULT_GLOBAL_HOTKEY, DEFAULT_IDLE_TIMEOUT_SECS, DEFAULT_MAX_TOP_EMOJIS,
+    DEFAULT_PLACE_UNDER_MOUSE, DEFAULT_SCALE_FACTOR, DEFAULT_START_ON_LOGIN,
+    DEFAULT_VISIBLE_ON_ALL_WORKSPACES, DEFAULT_WINDOW_HEIGHT, DEFAULT_WINDOW_WIDTH,
+    SETTINGS_WATCH_DEBOUNCE_MS,
 };
 use crate::errors::EmojiError;
+use crate::hotkey;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::thread;
+use tauri::{AppHandle, Emitter, Manager};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
@@ -25,11 +31,113 @@ impl Default for EmojiMode {
     }
 }
 
+/// How `paste_emoji` delivers the emoji to the previously focused app.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PasteStrategy {
+    /// Synthesize the emoji as text input via Enigo. Fast, but unreliable for
+    /// multi-codepoint sequences (ZWJ, skin tones, flags) in some apps.
+    TextInput,
+    /// Write the emoji to the clipboard and synthesize the platform paste
+    /// shortcut (Cmd+V / Ctrl+V) instead, restoring the prior clipboard
+    /// contents afterward. Slower, but works everywhere text input doesn't.
+    ClipboardPaste,
+}
+
+impl Default for PasteStrategy {
+    fn default() -> Self {
+        Self::TextInput
+    }
+}
+
+/// Language whose keywords are matched in addition to the bundled English
+/// `description`/`aliases`/`tags`, so e.g. "gato" or "chat" can find an emoji.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchLanguage {
+    En,
+    Es,
+    De,
+    Fr,
+    Zh,
+    Ja,
+}
+
+impl SearchLanguage {
+    /// File-name suffix used for the localized keyword overlay (e.g. `keywords.es.json`)
+    pub fn file_suffix(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Es => "es",
+            Self::De => "de",
+            Self::Fr => "fr",
+            Self::Zh => "zh",
+            Self::Ja => "ja",
+        }
+    }
+}
+
+impl Default for SearchLanguage {
+    fn default() -> Self {
+        Self::En
+    }
+}
+
+/// User's preferred Fitzpatrick skin tone, applied to skin-tone-base emoji
+/// (e.g. hand gestures) wherever they appear in search results. `Default`
+/// means the plain yellow emoji with no modifier.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SkinTone {
+    Default,
+    Light,
+    MediumLight,
+    Medium,
+    MediumDark,
+    Dark,
+}
+
+impl SkinTone {
+    /// The Fitzpatrick modifier code point for this tone, or `None` for `Default`.
+    pub fn modifier(&self) -> Option<char> {
+        match self {
+            Self::Default => None,
+            Self::Light => Some('\u{1F3FB}'),
+            Self::MediumLight => Some('\u{1F3FC}'),
+            Self::Medium => Some('\u{1F3FD}'),
+            Self::MediumDark => Some('\u{1F3FE}'),
+            Self::Dark => Some('\u{1F3FF}'),
+        }
+    }
+}
+
+impl Default for SkinTone {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+/// Which action a registered global hotkey triggers. `ShowPanel` is the
+/// original (and only mandatory) binding; the others let a shortcut drive the
+/// app without opening the picker at all.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    /// Opens/toggles the main emoji picker panel
+    ShowPanel,
+    /// Re-types the most-recently-used emoji without opening the panel
+    PasteRecent,
+    /// Re-types the most frequently used emoji without opening the panel
+    PasteTopRanked,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Settings {
-    /// Global hotkey to open the main panel (e.g., "Cmd+Option+Space")
-    #[serde(default = "default_global_hotkey")]
-    pub global_hotkey: String,
+    /// Named global hotkeys, keyed by the action each one triggers (e.g.
+    /// `ShowPanel` -> "Cmd+Option+Space"). `ShowPanel` is always present;
+    /// `PasteRecent`/`PasteTopRanked` are optional extra bindings.
+    #[serde(default = "default_hotkeys")]
+    pub hotkeys: HashMap<HotkeyAction, String>,
     /// Whether to place the main panel under the mouse cursor when shown
     #[serde(default = "default_place_under_mouse")]
     pub place_under_mouse: bool,
@@ -48,12 +156,41 @@ pub struct Settings {
     /// Scale factor for UI elements (0.5 to 2.0)
     #[serde(default = "default_scale_factor")]
     pub scale_factor: f64,
+    /// Whether to launch emojiq automatically at login
+    #[serde(default = "default_start_on_login")]
+    pub start_on_login: bool,
+    /// Whether the panel should join every macOS Space and float over fullscreen apps
+    #[serde(default = "default_visible_on_all_workspaces")]
+    pub visible_on_all_workspaces: bool,
+    /// Additional language to match emoji keywords against, alongside English
+    #[serde(default)]
+    pub search_language: SearchLanguage,
+    /// Preferred skin tone applied to skin-tone-base emoji in search results
+    #[serde(default)]
+    pub default_skin_tone: SkinTone,
+    /// Auto-hide the panel after this many seconds of no interaction (0 disables it)
+    #[serde(default = "default_idle_timeout")]
+    pub idle_timeout: u64,
+    /// How the selected emoji is delivered to the previously focused app
+    #[serde(default)]
+    pub paste_strategy: PasteStrategy,
+    /// With `EmojiMode::CopyOnly`, automatically paste the copied emoji into
+    /// the previously focused app once it regains focus, instead of leaving
+    /// the user to paste manually (macOS only; no-op elsewhere)
+    #[serde(default = "default_auto_paste")]
+    pub auto_paste: bool,
 }
 
 fn default_global_hotkey() -> String {
     DEFAULT_GLOBAL_HOTKEY.to_string()
 }
 
+fn default_hotkeys() -> HashMap<HotkeyAction, String> {
+    let mut hotkeys = HashMap::new();
+    hotkeys.insert(HotkeyAction::ShowPanel, default_global_hotkey());
+    hotkeys
+}
+
 fn default_window_width() -> f64 {
     DEFAULT_WINDOW_WIDTH
 }
@@ -74,25 +211,76 @@ fn default_place_under_mouse() -> bool {
     DEFAULT_PLACE_UNDER_MOUSE
 }
 
+fn default_start_on_login() -> bool {
+    DEFAULT_START_ON_LOGIN
+}
+
+fn default_visible_on_all_workspaces() -> bool {
+    DEFAULT_VISIBLE_ON_ALL_WORKSPACES
+}
+
+fn default_idle_timeout() -> u64 {
+    DEFAULT_IDLE_TIMEOUT_SECS
+}
+
+fn default_auto_paste() -> bool {
+    DEFAULT_AUTO_PASTE
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
-            global_hotkey: default_global_hotkey(),
+            hotkeys: default_hotkeys(),
             place_under_mouse: default_place_under_mouse(),
             emoji_mode: EmojiMode::default(),
             window_width: default_window_width(),
             window_height: default_window_height(),
             max_top_emojis: default_max_top_emojis(),
             scale_factor: default_scale_factor(),
+            start_on_login: default_start_on_login(),
+            visible_on_all_workspaces: default_visible_on_all_workspaces(),
+            search_language: SearchLanguage::default(),
+            default_skin_tone: SkinTone::default(),
+            idle_timeout: default_idle_timeout(),
+            paste_strategy: PasteStrategy::default(),
+            auto_paste: default_auto_paste(),
         }
     }
 }
 
+/// Validate every hotkey in `new_settings`, reverting its `hotkeys` map
+/// wholesale to `old_settings.hotkeys` if any entry fails to parse, so a
+/// single hand-edited, unparseable binding can't corrupt the rest. Returns
+/// whether `new_settings.hotkeys` was left as-is (`true`) or reverted
+/// (`false`).
+fn validate_and_merge_hotkeys(new_settings: &mut Settings, old_settings: &Settings) -> bool {
+    let mut hotkeys_valid = true;
+    for (action, hotkey_str) in &new_settings.hotkeys {
+        if let Err(e) = hotkey::parse_hotkey(hotkey_str) {
+            log::warn!(
+                "Live-reload: ignoring invalid hotkey '{}' for {:?}: {}",
+                hotkey_str,
+                action,
+                e
+            );
+            hotkeys_valid = false;
+            break;
+        }
+    }
+    if !hotkeys_valid {
+        new_settings.hotkeys = old_settings.hotkeys.clone();
+    }
+    hotkeys_valid
+}
+
 /// Manager for application settings with file persistence
 #[derive(Debug)]
 pub struct SettingsManager {
     settings: Arc<Mutex<Settings>>,
     settings_file_path: PathBuf,
+    // Raw JSON of our own last write, so the file watcher can ignore the
+    // change event it causes and avoid a reload loop.
+    last_saved_json: Arc<Mutex<Option<String>>>,
 }
 
 impl SettingsManager {
@@ -101,6 +289,7 @@ impl SettingsManager {
         Self {
             settings: Arc::new(Mutex::new(Settings::default())),
             settings_file_path,
+            last_saved_json: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -120,11 +309,20 @@ impl SettingsManager {
         let content = fs::read_to_string(&self.settings_file_path)?;
         let loaded_settings: Settings = serde_json::from_str(&content)?;
 
-        let mut settings = self
-            .settings
-            .lock()
-            .map_err(|e| EmojiError::Lock(format!("Failed to lock settings: {}", e)))?;
-        *settings = loaded_settings;
+        {
+            let mut settings = self
+                .settings
+                .lock()
+                .map_err(|e| EmojiError::Lock(format!("Failed to lock settings: {}", e)))?;
+            *settings = loaded_settings;
+        }
+
+        // Prime the watcher's own-write dedup with what we just loaded, so an
+        // unrelated filesystem event in the same directory (e.g. a ranks.json
+        // write) right after launch isn't mistaken for a hand-edit of this file.
+        if let Ok(mut last_saved) = self.last_saved_json.lock() {
+            *last_saved = Some(content);
+        }
 
         Ok(())
     }
@@ -137,7 +335,11 @@ impl SettingsManager {
             .map_err(|e| EmojiError::Lock(format!("Failed to lock settings: {}", e)))?;
 
         let json = serde_json::to_string_pretty(&*settings)?;
-        fs::write(&self.settings_file_path, json)?;
+        fs::write(&self.settings_file_path, &json)?;
+
+        if let Ok(mut last_saved) = self.last_saved_json.lock() {
+            *last_saved = Some(json);
+        }
 
         Ok(())
     }
@@ -186,4 +388,197 @@ impl SettingsManager {
         self.update(settings)?;
         Ok(())
     }
+
+    /// Watch `settings_file_path` for hand-edits (or sync from another machine)
+    /// and live-reload, debouncing rapid/coalesced writes (~250ms), so a
+    /// restart is never required to pick up an edited file.
+    pub fn start_watching(self: &Arc<Self>, app_handle: AppHandle) {
+        let manager = Arc::clone(self);
+        let path = self.settings_file_path.clone();
+
+        thread::spawn(move || {
+            use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut watcher: RecommendedWatcher = match notify::recommended_watcher(tx) {
+                Ok(w) => w,
+                Err(e) => {
+                    log::error!("Failed to create settings file watcher: {}", e);
+                    return;
+                }
+            };
+
+            let Some(parent) = path.parent() else {
+                log::error!("Settings file path has no parent directory, not watching");
+                return;
+            };
+            if let Err(e) = watcher.watch(parent, RecursiveMode::NonRecursive) {
+                log::error!("Failed to watch settings directory: {}", e);
+                return;
+            }
+
+            loop {
+                // Block for the first event, then drain whatever follows within the
+                // debounce window so a burst of writes collapses into one reload.
+                if rx.recv().is_err() {
+                    return; // watcher was dropped
+                }
+                let debounce = std::time::Duration::from_millis(SETTINGS_WATCH_DEBOUNCE_MS);
+                while rx.recv_timeout(debounce).is_ok() {}
+
+                manager.reload_and_apply(&app_handle);
+            }
+        });
+    }
+
+    /// Re-read the settings file after a watcher-observed change, re-registering
+    /// hotkeys and notifying the UI as `update_settings` does. Its other side
+    /// effects (autostart, search language, skin tone, workspace visibility)
+    /// are not applied here. Ignores events caused by our own `save()` writes.
+    fn reload_and_apply(self: &Arc<Self>, app_handle: &AppHandle) {
+        let content = match fs::read_to_string(&self.settings_file_path) {
+            Ok(content) => content,
+            Err(e) => {
+                log::warn!("Live-reload: failed to read settings.json: {}", e);
+                return;
+            }
+        };
+
+        if let Ok(last_saved) = self.last_saved_json.lock() {
+            if last_saved.as_deref() == Some(content.as_str()) {
+                return; // our own write; nothing to reload
+            }
+        }
+
+        // Partial/edited files deserialize fine: every field falls back to its
+        // `#[serde(default = ...)]` when absent, same as a fresh install.
+        let mut new_settings: Settings = match serde_json::from_str(&content) {
+            Ok(settings) => settings,
+            Err(e) => {
+                log::warn!("Live-reload: ignoring invalid settings.json: {}", e);
+                return;
+            }
+        };
+
+        let old_settings = self.get().unwrap_or_else(|_| Settings::default());
+
+        // Validate every hotkey before committing anything, same as
+        // `update_settings` - a hand-edited unparseable hotkey shouldn't
+        // corrupt the in-memory (and next unrelated `save()`'d) settings.
+        let hotkeys_valid = validate_and_merge_hotkeys(&mut new_settings, &old_settings);
+
+        match self.settings.lock() {
+            Ok(mut settings) => *settings = new_settings.clone(),
+            Err(e) => {
+                log::error!("Live-reload: failed to lock settings: {}", e);
+                return;
+            }
+        }
+        log::info!("Live-reloaded settings.json");
+
+        if hotkeys_valid && old_settings.hotkeys != new_settings.hotkeys {
+            if let Some(state) = app_handle.try_state::<crate::AppState>() {
+                let handle = app_handle.clone();
+                let manager = Arc::clone(self);
+                let reverted_hotkeys = old_settings.hotkeys.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = crate::command::reregister_hotkey(handle, state).await {
+                        log::error!(
+                            "Live-reload: failed to re-register hotkey, reverting to previous bindings: {}",
+                            e
+                        );
+
+                        // Mirror `update_settings`'s rollback: don't leave the
+                        // in-memory (and next-saved) settings pointing at a
+                        // binding the OS refused.
+                        if let Ok(mut settings) = manager.settings.lock() {
+                            settings.hotkeys = reverted_hotkeys;
+                        }
+                        let _ = manager.save();
+                    }
+                });
+            }
+        }
+
+        // Let the frontend pick up live changes (e.g. scale_factor, max_top_emojis)
+        if let Some(main_window) = app_handle.get_webview_window("main") {
+            let _ = main_window.emit("settings-changed", ());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_with_hotkey(hotkey_str: &str) -> Settings {
+        let mut settings = Settings::default();
+        settings
+            .hotkeys
+            .insert(HotkeyAction::ShowPanel, hotkey_str.to_string());
+        settings
+    }
+
+    #[test]
+    fn test_validate_and_merge_hotkeys_keeps_valid_hotkeys() {
+        let old_settings = settings_with_hotkey("Cmd+Option+Space");
+        let mut new_settings = settings_with_hotkey("Cmd+Shift+E");
+
+        let valid = validate_and_merge_hotkeys(&mut new_settings, &old_settings);
+
+        assert!(valid);
+        assert_eq!(
+            new_settings.hotkeys.get(&HotkeyAction::ShowPanel).unwrap(),
+            "Cmd+Shift+E"
+        );
+    }
+
+    #[test]
+    fn test_validate_and_merge_hotkeys_reverts_on_unparseable_hotkey() {
+        let old_settings = settings_with_hotkey("Cmd+Option+Space");
+        let mut new_settings = settings_with_hotkey("NotAHotkey");
+
+        let valid = validate_and_merge_hotkeys(&mut new_settings, &old_settings);
+
+        assert!(!valid);
+        assert_eq!(new_settings.hotkeys, old_settings.hotkeys);
+    }
+
+    #[test]
+    fn test_validate_and_merge_hotkeys_one_bad_entry_reverts_the_whole_map() {
+        // A hand-edited settings.json could have several hotkeys; one bad
+        // entry shouldn't let the others through while that one is dropped -
+        // the whole map reverts together, matching `update_settings`'s
+        // rollback-on-failure behavior.
+        let old_settings = settings_with_hotkey("Cmd+Option+Space");
+        let mut new_settings = Settings::default();
+        new_settings
+            .hotkeys
+            .insert(HotkeyAction::ShowPanel, "Cmd+Shift+E".to_string());
+        new_settings
+            .hotkeys
+            .insert(HotkeyAction::PasteRecent, "NotAHotkey".to_string());
+
+        let valid = validate_and_merge_hotkeys(&mut new_settings, &old_settings);
+
+        assert!(!valid);
+        assert_eq!(new_settings.hotkeys, old_settings.hotkeys);
+    }
+
+    #[test]
+    fn test_load_primes_last_saved_json_dedup() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let settings_path = temp_dir.path().join("settings.json");
+        fs::write(&settings_path, serde_json::to_string(&Settings::default()).unwrap()).unwrap();
+
+        let manager = SettingsManager::new(settings_path);
+        manager.load().unwrap();
+
+        // A subsequent unrelated filesystem event (e.g. a ranks.json write in
+        // the same directory) must not be mistaken for a hand-edit: the
+        // watcher's dedup should already hold exactly what's on disk.
+        let on_disk = fs::read_to_string(&manager.settings_file_path).unwrap();
+        let last_saved = manager.last_saved_json.lock().unwrap().clone();
+        assert_eq!(last_saved, Some(on_disk));
+    }
 }
@@ -0,0 +1,175 @@
+use phf::phf_map;
+use serde::Serialize;
+
+/// Common CLDR/GitHub shortcode -> emoji table, built at compile time so
+/// lookups are a single allocation-free hash probe instead of a linear scan
+/// over `emoji.json`'s aliases. Not exhaustive: it covers the shortcodes
+/// people actually type (greetings, reactions, common gestures/objects), not
+/// every emoji in the set.
+pub static SHORTCODES: phf::Map<&'static str, &'static str> = phf_map! {
+    "grinning" => "😀",
+    "smile" => "😄",
+    "joy" => "😂",
+    "wink" => "😉",
+    "blush" => "😊",
+    "thinking" => "🤔",
+    "thinking_face" => "🤔",
+    "neutral_face" => "😐",
+    "cry" => "😢",
+    "sob" => "😭",
+    "rage" => "😡",
+    "scream" => "😱",
+    "sunglasses" => "😎",
+    "wave" => "👋",
+    "thumbsup" => "👍",
+    "+1" => "👍",
+    "thumbsdown" => "👎",
+    "-1" => "👎",
+    "clap" => "👏",
+    "pray" => "🙏",
+    "muscle" => "💪",
+    "ok_hand" => "👌",
+    "point_up" => "☝️",
+    "eyes" => "👀",
+    "heart" => "❤️",
+    "broken_heart" => "💔",
+    "fire" => "🔥",
+    "100" => "💯",
+    "tada" => "🎉",
+    "confetti_ball" => "🎊",
+    "rocket" => "🚀",
+    "star" => "⭐",
+    "sparkles" => "✨",
+    "zap" => "⚡",
+    "warning" => "⚠️",
+    "white_check_mark" => "✅",
+    "x" => "❌",
+    "question" => "❓",
+    "exclamation" => "❗",
+    "bulb" => "💡",
+    "bug" => "🐛",
+    "rocket_ship" => "🚀",
+    "coffee" => "☕",
+    "pizza" => "🍕",
+    "beers" => "🍻",
+    "cake" => "🍰",
+    "sun" => "☀️",
+    "moon" => "🌙",
+    "cloud" => "☁️",
+    "umbrella" => "☂️",
+    "snowflake" => "❄️",
+    "dog" => "🐶",
+    "cat" => "🐱",
+    "unicorn" => "🦄",
+    "computer" => "💻",
+    "phone" => "📱",
+    "email" => "📧",
+    "lock" => "🔒",
+    "key" => "🔑",
+    "gear" => "⚙️",
+    "hourglass" => "⏳",
+    "calendar" => "📅",
+    "book" => "📖",
+    "moneybag" => "💰",
+    "gift" => "🎁",
+    "trophy" => "🏆",
+    "checkered_flag" => "🏁",
+    "world_map" => "🗺️",
+    "house" => "🏠",
+    "car" => "🚗",
+    "airplane" => "✈️",
+};
+
+/// Look up a bare shortcode name (no surrounding colons). The lookup is
+/// case-sensitive; callers should lowercase first.
+pub fn resolve(name: &str) -> Option<&'static str> {
+    SHORTCODES.get(name).copied()
+}
+
+/// A `:shortcode:` token found in a string, with the byte range it occupies
+/// (including the colons) and the emoji it resolves to.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShortcodeMatch {
+    pub start: usize,
+    pub end: usize,
+    pub emoji: String,
+}
+
+/// Scan `text` for `:shortcode:` tokens and resolve each one, the way an
+/// editor auto-replaces `:wave:` as you type. Overlapping candidates are not
+/// possible since a resolved match consumes its closing colon.
+pub fn scan(text: &str) -> Vec<ShortcodeMatch> {
+    let mut matches = Vec::new();
+    let mut search_from = 0;
+
+    while let Some(open_rel) = text[search_from..].find(':') {
+        let open = search_from + open_rel;
+        let Some(close_rel) = text[open + 1..].find(':') else {
+            break;
+        };
+        let close = open + 1 + close_rel;
+        let name = &text[open + 1..close];
+
+        let is_valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'));
+
+        if is_valid_name {
+            if let Some(emoji) = resolve(&name.to_lowercase()) {
+                matches.push(ShortcodeMatch {
+                    start: open,
+                    end: close + 1,
+                    emoji: emoji.to_string(),
+                });
+                search_from = close + 1;
+                continue;
+            }
+        }
+
+        search_from = open + 1;
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_known_shortcode() {
+        assert_eq!(resolve("wave"), Some("👋"));
+    }
+
+    #[test]
+    fn unknown_shortcode_resolves_to_none() {
+        assert_eq!(resolve("not_a_real_shortcode"), None);
+    }
+
+    #[test]
+    fn scans_multiple_tokens_in_text() {
+        let found = scan("hey :wave: nice to meet you :tada:!");
+        assert_eq!(
+            found,
+            vec![
+                ShortcodeMatch {
+                    start: 4,
+                    end: 10,
+                    emoji: "👋".to_string()
+                },
+                ShortcodeMatch {
+                    start: 28,
+                    end: 34,
+                    emoji: "🎉".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_unknown_and_unterminated_tokens() {
+        assert_eq!(scan("not a shortcode: just a colon"), vec![]);
+        assert_eq!(scan(":unknown_shortcode:"), vec![]);
+    }
+}
@@ -1,11 +1,26 @@
 use crate::constants::{
     HELP_WINDOW_HEIGHT, HELP_WINDOW_WIDTH, SETTINGS_WINDOW_HEIGHT, SETTINGS_WINDOW_WIDTH,
+    TITLEBAR_TRAFFIC_LIGHTS_INSET_X, TITLEBAR_TRAFFIC_LIGHTS_INSET_Y,
 };
 use tauri::{
     menu::{Menu, MenuItem},
     tray::TrayIconBuilder,
     AppHandle, Manager, WebviewUrl, WebviewWindowBuilder,
 };
+#[cfg(target_os = "macos")]
+use tauri::{TitleBarStyle, WebviewWindow};
+#[cfg(target_os = "macos")]
+use tauri_plugin_decorum::WebviewWindowExt as _;
+
+/// Give a Settings/Help window a chromeless, panel-matching look: native
+/// decorations off, traffic lights kept but inset over a transparent overlay
+/// titlebar instead of a standard title bar row. macOS only; other platforms
+/// keep the builder's normal decorations.
+#[cfg(target_os = "macos")]
+fn apply_overlay_titlebar(window: &WebviewWindow) {
+    window.create_overlay_titlebar();
+    window.set_traffic_lights_inset(TITLEBAR_TRAFFIC_LIGHTS_INSET_X, TITLEBAR_TRAFFIC_LIGHTS_INSET_Y);
+}
 
 pub fn init(app_handle: &AppHandle) -> tauri::Result<()> {
     let help_i = MenuItem::with_id(app_handle, "help", "Help", true, None::<&str>)?;
@@ -46,14 +61,25 @@ pub fn open_settings_window(app: &AppHandle) -> tauri::Result<()> {
     }
 
     // Create new settings window
-    let window =
+    let mut builder =
         WebviewWindowBuilder::new(app, "settings", WebviewUrl::App("settings.html".into()))
             .title("Settings - emojiq")
             .inner_size(SETTINGS_WINDOW_WIDTH, SETTINGS_WINDOW_HEIGHT)
             .resizable(false)
             .center()
-            .focused(true)
-            .build()?;
+            .focused(true);
+
+    #[cfg(target_os = "macos")]
+    {
+        builder = builder
+            .hidden_title(true)
+            .title_bar_style(TitleBarStyle::Overlay);
+    }
+
+    let window = builder.build()?;
+
+    #[cfg(target_os = "macos")]
+    apply_overlay_titlebar(&window);
 
     // Explicitly set focus to ensure it gets it
     window.set_focus()?;
@@ -68,14 +94,25 @@ pub fn open_help_window(app: &AppHandle) -> tauri::Result<()> {
         return Ok(());
     }
 
-    let window = WebviewWindowBuilder::new(app, "help", WebviewUrl::App("help.html".into()))
+    let mut builder = WebviewWindowBuilder::new(app, "help", WebviewUrl::App("help.html".into()))
         .title("Shortcuts - emojiq")
         .inner_size(HELP_WINDOW_WIDTH, HELP_WINDOW_HEIGHT)
         .resizable(false)
         .always_on_top(true)
         .center()
-        .focused(true)
-        .build()?;
+        .focused(true);
+
+    #[cfg(target_os = "macos")]
+    {
+        builder = builder
+            .hidden_title(true)
+            .title_bar_style(TitleBarStyle::Overlay);
+    }
+
+    let window = builder.build()?;
+
+    #[cfg(target_os = "macos")]
+    apply_overlay_titlebar(&window);
 
     window.set_focus()?;
     Ok(())
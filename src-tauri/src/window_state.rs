@@ -0,0 +1,269 @@
+use crate::errors::EmojiError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+bitflags::bitflags! {
+    /// Which aspects of window geometry get persisted and restored.
+    ///
+    /// Modeled on tauri-plugin-window-state's `StateFlags`, so callers can opt
+    /// out of e.g. restoring position while still restoring size.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct StateFlags: u32 {
+        const SIZE = 0b0000_0001;
+        const POSITION = 0b0000_0010;
+        const SCALE = 0b0000_0100;
+    }
+}
+
+impl Default for StateFlags {
+    fn default() -> Self {
+        Self::SIZE | Self::POSITION
+    }
+}
+
+/// Persisted panel geometry. Fields are optional since a fresh install has
+/// nothing to restore, and individual aspects may be disabled via `StateFlags`.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct WindowState {
+    pub x: Option<f64>,
+    pub y: Option<f64>,
+    pub width: Option<f64>,
+    pub height: Option<f64>,
+    pub scale_factor: Option<f64>,
+}
+
+/// Manager for persisted panel window state (position/size/scale), stored
+/// separately from `Settings` under `app_data_dir`.
+#[derive(Debug)]
+pub struct WindowStateManager {
+    state: Arc<Mutex<WindowState>>,
+    state_file_path: PathBuf,
+    flags: StateFlags,
+}
+
+impl WindowStateManager {
+    /// Create a new window state manager with the given file path and flags
+    pub fn new(state_file_path: PathBuf, flags: StateFlags) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(WindowState::default())),
+            state_file_path,
+            flags,
+        }
+    }
+
+    /// Initialize state by loading from file. A missing file leaves the default
+    /// (empty) state; a corrupt one returns an error and also leaves it.
+    pub fn initialize(&self) -> Result<(), EmojiError> {
+        if self.state_file_path.exists() {
+            self.load()?;
+        }
+        Ok(())
+    }
+
+    /// Load window state from file
+    fn load(&self) -> Result<(), EmojiError> {
+        let content = fs::read_to_string(&self.state_file_path)?;
+        let loaded_state: WindowState = serde_json::from_str(&content)?;
+
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+        *state = loaded_state;
+
+        Ok(())
+    }
+
+    /// Save window state to file
+    pub fn save(&self) -> Result<(), EmojiError> {
+        let state = self
+            .state
+            .lock()
+            .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+
+        let json = serde_json::to_string_pretty(&*state)?;
+        fs::write(&self.state_file_path, json)?;
+
+        Ok(())
+    }
+
+    /// Get the current window state
+    pub fn get(&self) -> Result<WindowState, EmojiError> {
+        let state = self
+            .state
+            .lock()
+            .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+        Ok(*state)
+    }
+
+    /// Record the panel's screen position, if `POSITION` is enabled
+    pub fn update_position(&self, x: f64, y: f64) -> Result<(), EmojiError> {
+        if !self.flags.contains(StateFlags::POSITION) {
+            return Ok(());
+        }
+        {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+            state.x = Some(x);
+            state.y = Some(y);
+        }
+        self.save()
+    }
+
+    /// Record the panel's size, if `SIZE` is enabled
+    pub fn update_size(&self, width: f64, height: f64) -> Result<(), EmojiError> {
+        if !self.flags.contains(StateFlags::SIZE) {
+            return Ok(());
+        }
+        {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+            state.width = Some(width);
+            state.height = Some(height);
+        }
+        self.save()
+    }
+
+    /// Record the panel's scale factor, if `SCALE` is enabled
+    pub fn update_scale_factor(&self, scale_factor: f64) -> Result<(), EmojiError> {
+        if !self.flags.contains(StateFlags::SCALE) {
+            return Ok(());
+        }
+        {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|e| EmojiError::Lock(format!("Failed to lock window state: {}", e)))?;
+            state.scale_factor = Some(scale_factor);
+        }
+        self.save()
+    }
+
+    /// Whether restoring the given aspect is enabled
+    pub fn restores(&self, flag: StateFlags) -> bool {
+        self.flags.contains(flag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_path(temp_dir: &tempfile::TempDir) -> PathBuf {
+        temp_dir.path().join("window-state.json")
+    }
+
+    #[test]
+    fn test_update_position_round_trips_through_save_and_load() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::POSITION);
+
+        manager.update_position(12.0, 34.0).unwrap();
+
+        let reloaded = WindowStateManager::new(state_path(&temp_dir), StateFlags::POSITION);
+        reloaded.initialize().unwrap();
+        let state = reloaded.get().unwrap();
+        assert_eq!(state.x, Some(12.0));
+        assert_eq!(state.y, Some(34.0));
+    }
+
+    #[test]
+    fn test_update_position_is_a_no_op_without_position_flag() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::SIZE);
+
+        manager.update_position(12.0, 34.0).unwrap();
+
+        // Nothing should even be written, since POSITION isn't enabled.
+        assert!(!state_path(&temp_dir).exists());
+        let state = manager.get().unwrap();
+        assert_eq!(state.x, None);
+        assert_eq!(state.y, None);
+    }
+
+    #[test]
+    fn test_update_size_round_trips_through_save_and_load() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::SIZE);
+
+        manager.update_size(400.0, 300.0).unwrap();
+
+        let reloaded = WindowStateManager::new(state_path(&temp_dir), StateFlags::SIZE);
+        reloaded.initialize().unwrap();
+        let state = reloaded.get().unwrap();
+        assert_eq!(state.width, Some(400.0));
+        assert_eq!(state.height, Some(300.0));
+    }
+
+    #[test]
+    fn test_update_size_is_a_no_op_without_size_flag() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::POSITION);
+
+        manager.update_size(400.0, 300.0).unwrap();
+
+        assert!(!state_path(&temp_dir).exists());
+        let state = manager.get().unwrap();
+        assert_eq!(state.width, None);
+        assert_eq!(state.height, None);
+    }
+
+    #[test]
+    fn test_update_scale_factor_round_trips_through_save_and_load() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::SCALE);
+
+        manager.update_scale_factor(1.5).unwrap();
+
+        let reloaded = WindowStateManager::new(state_path(&temp_dir), StateFlags::SCALE);
+        reloaded.initialize().unwrap();
+        let state = reloaded.get().unwrap();
+        assert_eq!(state.scale_factor, Some(1.5));
+    }
+
+    #[test]
+    fn test_update_scale_factor_is_a_no_op_without_scale_flag() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manager = WindowStateManager::new(state_path(&temp_dir), StateFlags::default());
+
+        manager.update_scale_factor(1.5).unwrap();
+
+        assert!(!state_path(&temp_dir).exists());
+        let state = manager.get().unwrap();
+        assert_eq!(state.scale_factor, None);
+    }
+
+    #[test]
+    fn test_all_flags_together_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let flags = StateFlags::SIZE | StateFlags::POSITION | StateFlags::SCALE;
+        let manager = WindowStateManager::new(state_path(&temp_dir), flags);
+
+        manager.update_position(1.0, 2.0).unwrap();
+        manager.update_size(3.0, 4.0).unwrap();
+        manager.update_scale_factor(2.0).unwrap();
+
+        let reloaded = WindowStateManager::new(state_path(&temp_dir), flags);
+        reloaded.initialize().unwrap();
+        let state = reloaded.get().unwrap();
+        assert_eq!(state.x, Some(1.0));
+        assert_eq!(state.y, Some(2.0));
+        assert_eq!(state.width, Some(3.0));
+        assert_eq!(state.height, Some(4.0));
+        assert_eq!(state.scale_factor, Some(2.0));
+    }
+
+    #[test]
+    fn test_restores_reflects_flags() {
+        let manager = WindowStateManager::new(PathBuf::from("unused.json"), StateFlags::SIZE);
+        assert!(manager.restores(StateFlags::SIZE));
+        assert!(!manager.restores(StateFlags::POSITION));
+        assert!(!manager.restores(StateFlags::SCALE));
+    }
+}
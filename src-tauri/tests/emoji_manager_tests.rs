@@ -1,11 +1,18 @@
-use emojiq_lib::emoji_manager::{EmojiData, EmojiManager};
+use emojiq_lib::emoji_manager::{EmojiData, EmojiManager, UsageRecord};
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tempfile::TempDir;
 
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 // Sample emoji data for testing
 fn create_test_emoji_data() -> Vec<EmojiData> {
     vec![
@@ -17,6 +24,8 @@ fn create_test_emoji_data() -> Vec<EmojiData> {
             tags: Some(vec!["smile".to_string(), "happy".to_string()]),
             unicode_version: Some("6.1".to_string()),
             ios_version: Some("6.0".to_string()),
+            skin_tone_base: false,
+            variants: None,
         },
         EmojiData {
             emoji: "😃".to_string(),
@@ -30,6 +39,8 @@ fn create_test_emoji_data() -> Vec<EmojiData> {
             ]),
             unicode_version: Some("6.0".to_string()),
             ios_version: Some("6.0".to_string()),
+            skin_tone_base: false,
+            variants: None,
         },
         EmojiData {
             emoji: "📆".to_string(),
@@ -39,6 +50,8 @@ fn create_test_emoji_data() -> Vec<EmojiData> {
             tags: Some(vec!["schedule".to_string()]),
             unicode_version: Some("6.0".to_string()),
             ios_version: Some("6.0".to_string()),
+            skin_tone_base: false,
+            variants: None,
         },
         EmojiData {
             emoji: "🐒".to_string(),
@@ -48,6 +61,8 @@ fn create_test_emoji_data() -> Vec<EmojiData> {
             tags: Some(vec!["animal".to_string()]),
             unicode_version: Some("6.0".to_string()),
             ios_version: Some("6.0".to_string()),
+            skin_tone_base: false,
+            variants: None,
         },
         EmojiData {
             emoji: "🐵".to_string(),
@@ -57,6 +72,8 @@ fn create_test_emoji_data() -> Vec<EmojiData> {
             tags: Some(vec!["animal".to_string(), "monkey".to_string()]),
             unicode_version: Some("6.0".to_string()),
             ios_version: Some("6.0".to_string()),
+            skin_tone_base: false,
+            variants: None,
         },
     ]
 }
@@ -139,10 +156,14 @@ fn test_load_ranks() {
     let data = manager.data.read().unwrap();
     assert!(data.ranks_loaded);
     assert_eq!(data.ranks.len(), 4);
-    assert_eq!(data.ranks.get("👀"), Some(&6));
-    assert_eq!(data.ranks.get("🧡"), Some(&5));
-    assert_eq!(data.ranks.get("🎉"), Some(&3));
-    assert_eq!(data.ranks.get("🐒"), Some(&1));
+    assert_eq!(data.ranks.get("👀").map(|r| r.count), Some(6));
+    assert_eq!(data.ranks.get("🧡").map(|r| r.count), Some(5));
+    assert_eq!(data.ranks.get("🎉").map(|r| r.count), Some(3));
+    assert_eq!(data.ranks.get("🐒").map(|r| r.count), Some(1));
+
+    // Legacy bare-integer entries (written by `create_test_ranks_data`) have
+    // no recency info until they're used again.
+    assert!(data.ranks.get("👀").unwrap().recent_uses.is_empty());
 }
 
 #[test]
@@ -276,18 +297,20 @@ fn test_increment_usage() {
     manager.load_ranks().unwrap();
     let initial_count = {
         let data = manager.data.read().unwrap();
-        data.ranks.get("😀").copied().unwrap_or(0)
+        data.ranks.get("😀").map(|r| r.count).unwrap_or(0)
     };
 
     // Increment usage
     manager.increment_usage("😀", None).unwrap();
 
-    // Check that count was incremented
-    let new_count = {
+    // Check that count was incremented and a recency timestamp was recorded
+    let (new_count, recent_uses_len) = {
         let data = manager.data.read().unwrap();
-        data.ranks.get("😀").copied().unwrap_or(0)
+        let record = data.ranks.get("😀").unwrap();
+        (record.count, record.recent_uses.len())
     };
     assert_eq!(new_count, initial_count + 1);
+    assert_eq!(recent_uses_len, 1);
 
     // Wait for potential file write
     thread::sleep(Duration::from_millis(100));
@@ -306,7 +329,7 @@ fn test_increment_usage_new_emoji() {
     // Should start at 1
     let count = {
         let data = manager.data.read().unwrap();
-        data.ranks.get("🚀").copied().unwrap_or(0)
+        data.ranks.get("🚀").map(|r| r.count).unwrap_or(0)
     };
     assert_eq!(count, 1);
 
@@ -381,3 +404,344 @@ fn test_get_emojis_with_zero_max_top() {
     assert!(result.contains(&"🐒".to_string()));
     assert!(result.contains(&"🐵".to_string()));
 }
+
+#[test]
+fn test_skin_tone_variants() {
+    use emojiq_lib::settings::SkinTone;
+
+    let temp_dir = TempDir::new().unwrap();
+    let emoji_file = temp_dir.path().join("emoji.json");
+    let ranks_file = temp_dir.path().join("ranks.json");
+
+    let emoji_data = vec![EmojiData {
+        emoji: "👋".to_string(),
+        description: Some("waving hand".to_string()),
+        category: Some("People & Body".to_string()),
+        aliases: Some(vec!["wave".to_string()]),
+        tags: Some(vec!["hand".to_string(), "hello".to_string()]),
+        unicode_version: Some("6.0".to_string()),
+        ios_version: Some("6.0".to_string()),
+        skin_tone_base: true,
+        variants: None,
+    }];
+    fs::write(
+        &emoji_file,
+        serde_json::to_string_pretty(&emoji_data).unwrap(),
+    )
+    .unwrap();
+
+    let manager = EmojiManager::new(emoji_file, ranks_file);
+    manager.initialize().unwrap();
+
+    // The base is expanded into its five Fitzpatrick variants, reachable via get_variants
+    let variants = manager.get_variants("👋").unwrap();
+    assert_eq!(variants.len(), 5);
+    assert_eq!(variants[0], "👋\u{1F3FB}");
+    assert_eq!(variants[4], "👋\u{1F3FF}");
+
+    // A variant passed back in also resolves to the same list
+    assert_eq!(manager.get_variants(&variants[2]).unwrap(), variants);
+
+    // With no preferred tone, search returns the plain base
+    assert_eq!(manager.get_emojis("wave", 10).unwrap(), vec!["👋"]);
+
+    // Once a preferred tone is set, the base is substituted with that variant
+    manager.set_default_skin_tone(SkinTone::Medium).unwrap();
+    assert_eq!(manager.get_emojis("wave", 10).unwrap(), vec![variants[2].clone()]);
+}
+
+#[test]
+fn test_custom_overlay_and_packs_merge() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, ranks_file) = setup_test_files(&temp_dir);
+
+    // Overlay adds a new shortcode to an existing bundled emoji
+    let overlay_path = temp_dir.path().join("custom-emoji.json");
+    fs::write(
+        &overlay_path,
+        r#"[{"emoji": "😀", "aliases": ["org_mascot"]}]"#,
+    )
+    .unwrap();
+
+    // A pack directory contributes a brand-new emoji entry
+    let packs_dir = temp_dir.path().join("packs");
+    fs::create_dir_all(&packs_dir).unwrap();
+    fs::write(
+        packs_dir.join("company.json"),
+        r#"[{"emoji": "🦄", "description": "company logo", "aliases": ["company_logo"]}]"#,
+    )
+    .unwrap();
+
+    let manager = EmojiManager::new(emoji_file, ranks_file)
+        .with_custom_sources(Some(overlay_path), Some(packs_dir));
+    manager.initialize().unwrap();
+
+    // Existing bundled emoji gained the overlay alias without duplicating the entry
+    let data = manager.data.read().unwrap();
+    assert_eq!(data.emojis.iter().filter(|e| e.emoji == "😀").count(), 1);
+    assert!(data
+        .emojis
+        .iter()
+        .find(|e| e.emoji == "😀")
+        .unwrap()
+        .aliases
+        .as_ref()
+        .unwrap()
+        .contains(&"org_mascot".to_string()));
+    drop(data);
+
+    // New emoji from the pack is searchable under its alias
+    let result = manager.get_emojis("company_logo", 10).unwrap();
+    assert!(result.contains(&"🦄".to_string()));
+
+    // reload_packs picks up a newly added pack without restarting
+    fs::write(
+        packs_dir.join("more.json"),
+        r#"[{"emoji": "🎨", "description": "palette", "aliases": ["company_art"]}]"#,
+    )
+    .unwrap();
+    manager.reload_packs().unwrap();
+    let result = manager.get_emojis("company_art", 10).unwrap();
+    assert!(result.contains(&"🎨".to_string()));
+}
+
+#[test]
+fn test_fuzzy_fallback_for_typos() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, ranks_file) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, ranks_file);
+    manager.initialize().unwrap();
+
+    // No exact/prefix hits for a one-letter-off typo, but the fuzzy fallback
+    // should still surface the intended emoji.
+    assert!(manager
+        .get_emojis("calendr", 10)
+        .unwrap()
+        .contains(&"📆".to_string()));
+    assert!(manager
+        .get_emojis("mnkey", 10)
+        .unwrap()
+        .iter()
+        .any(|e| e == "🐒" || e == "🐵"));
+
+    // An exact match with few hits still runs the fuzzy pass, but gains no unrelated noise from it
+    let exact = manager.get_emojis("calendar", 10).unwrap();
+    assert_eq!(exact, vec!["📆".to_string()]);
+}
+
+#[test]
+fn test_shortcode_resolution() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, ranks_file) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, ranks_file);
+    manager.initialize().unwrap();
+
+    // `:shortcode:` resolves via the compile-time phf table, independent of
+    // whatever's in the (test fixture) emoji.json.
+    assert_eq!(manager.get_emojis(":wave:", 10).unwrap(), vec!["👋".to_string()]);
+
+    // A bare shortcode falls back to the same table when the index has no hits.
+    assert_eq!(manager.get_emojis("wave", 10).unwrap(), vec!["👋".to_string()]);
+}
+
+#[test]
+fn test_recent_emoji_outranks_stale_but_frequent_emoji() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+    manager.build_keywords().unwrap();
+    manager.build_index().unwrap();
+
+    let now = now_secs();
+    {
+        let mut data = manager.data.write().unwrap();
+        data.ranks_loaded = true;
+        // Heavily used, but every use is months stale.
+        data.ranks.insert(
+            "🐒".to_string(),
+            UsageRecord {
+                count: 100,
+                recent_uses: vec![now - 200 * 24 * 60 * 60],
+            },
+        );
+        // Used only once, but just now.
+        data.ranks.insert(
+            "🐵".to_string(),
+            UsageRecord {
+                count: 1,
+                recent_uses: vec![now],
+            },
+        );
+    }
+
+    let result = manager.get_emojis("", 2).unwrap();
+    let stale_pos = result.iter().position(|e| e == "🐒").unwrap();
+    let recent_pos = result.iter().position(|e| e == "🐵").unwrap();
+    assert!(
+        recent_pos < stale_pos,
+        "recently-used emoji should outrank a stale-but-frequent one: {:?}",
+        result
+    );
+}
+
+#[test]
+fn test_get_categories_empty_ranks_has_no_synthetic_categories() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+
+    let categories = manager.get_categories().unwrap();
+    assert_eq!(
+        categories,
+        vec![
+            "Smileys & Emotion".to_string(),
+            "Objects".to_string(),
+            "Animals & Nature".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_get_categories_dedups_and_preserves_first_seen_order() {
+    // The fixture data already repeats "Smileys & Emotion" and
+    // "Animals & Nature" across multiple emoji; each should appear only once,
+    // in the order its category was first seen.
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+
+    let categories = manager.get_categories().unwrap();
+    let unique: std::collections::HashSet<&String> = categories.iter().collect();
+    assert_eq!(categories.len(), unique.len());
+}
+
+#[test]
+fn test_get_categories_frequently_used_without_recent() {
+    // Legacy (bare-integer) ranks have counts but no recency info, so
+    // "Frequently Used" applies but "Recent" shouldn't.
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, ranks_file) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, ranks_file);
+    manager.load_emojis().unwrap();
+    manager.load_ranks().unwrap();
+
+    let categories = manager.get_categories().unwrap();
+    assert!(categories.contains(&"Frequently Used".to_string()));
+    assert!(!categories.contains(&"Recent".to_string()));
+}
+
+#[test]
+fn test_get_categories_recent_appears_with_recency_data() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+    {
+        let mut data = manager.data.write().unwrap();
+        data.ranks.insert(
+            "🐒".to_string(),
+            UsageRecord {
+                count: 1,
+                recent_uses: vec![now_secs()],
+            },
+        );
+    }
+
+    let categories = manager.get_categories().unwrap();
+    assert!(categories.contains(&"Frequently Used".to_string()));
+    assert!(categories.contains(&"Recent".to_string()));
+}
+
+#[test]
+fn test_get_emojis_by_category_real_category() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.initialize().unwrap();
+
+    let result = manager.get_emojis_by_category("Animals & Nature", 10).unwrap();
+    assert_eq!(result, vec!["🐒".to_string(), "🐵".to_string()]);
+}
+
+#[test]
+fn test_get_emojis_by_category_frequently_used() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+    {
+        let mut data = manager.data.write().unwrap();
+        data.ranks.insert(
+            "🐒".to_string(),
+            UsageRecord {
+                count: 5,
+                recent_uses: vec![now_secs(), now_secs()],
+            },
+        );
+        data.ranks.insert(
+            "😀".to_string(),
+            UsageRecord {
+                count: 1,
+                recent_uses: vec![now_secs()],
+            },
+        );
+    }
+
+    let result = manager
+        .get_emojis_by_category("Frequently Used", 10)
+        .unwrap();
+    assert!(result.contains(&"🐒".to_string()));
+    assert!(result.contains(&"😀".to_string()));
+    // Higher frecency score (more/recenter uses) sorts first.
+    assert_eq!(result[0], "🐒");
+}
+
+#[test]
+fn test_get_emojis_by_category_recent() {
+    let temp_dir = TempDir::new().unwrap();
+    let (emoji_file, _) = setup_test_files(&temp_dir);
+
+    let manager = EmojiManager::new(emoji_file, temp_dir.path().join("test_ranks.json"));
+    manager.load_emojis().unwrap();
+    let now = now_secs();
+    {
+        let mut data = manager.data.write().unwrap();
+        data.ranks.insert(
+            "🐒".to_string(),
+            UsageRecord {
+                count: 1,
+                recent_uses: vec![now - 100],
+            },
+        );
+        data.ranks.insert(
+            "😀".to_string(),
+            UsageRecord {
+                count: 1,
+                recent_uses: vec![now],
+            },
+        );
+        // No recency info: a legacy entry shouldn't show up in "Recent" at all.
+        data.ranks.insert(
+            "📆".to_string(),
+            UsageRecord {
+                count: 50,
+                recent_uses: Vec::new(),
+            },
+        );
+    }
+
+    let result = manager.get_emojis_by_category("Recent", 10).unwrap();
+    assert_eq!(result, vec!["😀".to_string(), "🐒".to_string()]);
+}